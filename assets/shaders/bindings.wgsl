@@ -0,0 +1,10 @@
+@group(0) @binding(3)
+var<uniform> uni: f32;
+
+@group(2) @binding(1)
+var<storage, read_write> values: array<f32>;
+
+@compute @workgroup_size(1)
+fn main(@builtin(global_invocation_id) invocation_id: vec3<u32>) {
+    values[invocation_id.x] = values[invocation_id.x] * uni;
+}
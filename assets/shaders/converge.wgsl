@@ -0,0 +1,14 @@
+@group(0) @binding(0)
+var<storage, read_write> values: array<u32>;
+
+@group(0) @binding(1)
+var<storage, read_write> changed: atomic<u32>;
+
+// Raise every value by one until it reaches 10
+@compute @workgroup_size(1)
+fn main(@builtin(global_invocation_id) invocation_id: vec3<u32>) {
+    if values[invocation_id.x] < 10u {
+        values[invocation_id.x] += 1u;
+        atomicStore(&changed, 1u);
+    }
+}
@@ -0,0 +1,12 @@
+@group(0) @binding(0)
+var<storage, read_write> values: array<u32>;
+
+@compute @workgroup_size(4)
+fn main(
+    @builtin(global_invocation_id) invocation_id: vec3<u32>,
+    @builtin(workgroup_id) workgroup_id: vec3<u32>,
+) {
+    if invocation_id.x < arrayLength(&values) {
+        values[invocation_id.x] = workgroup_id.x + 1u;
+    }
+}
@@ -0,0 +1,23 @@
+@group(0) @binding(0)
+var<storage, read_write> values: array<f32>;
+
+@group(0) @binding(1)
+var<storage, read_write> args: array<u32, 3>;
+
+// Count the positive values, one workgroup per value
+@compute @workgroup_size(1)
+fn count() {
+    var count = 0u;
+    for (var i = 0u; i < arrayLength(&values); i++) {
+        if values[i] > 0.0 {
+            count++;
+        }
+    }
+
+    args = array<u32, 3>(count, 1u, 1u);
+}
+
+@compute @workgroup_size(1)
+fn increment(@builtin(global_invocation_id) invocation_id: vec3<u32>) {
+    values[invocation_id.x] = values[invocation_id.x] + 1.0;
+}
@@ -0,0 +1,15 @@
+struct Segment {
+    start: vec3<f32>,
+    end: vec3<f32>,
+}
+
+@group(0) @binding(0)
+var<storage, read> segment: Segment;
+
+@group(0) @binding(1)
+var<storage, read_write> lengths: array<f32>;
+
+@compute @workgroup_size(1)
+fn main() {
+    lengths[0] = distance(segment.start, segment.end);
+}
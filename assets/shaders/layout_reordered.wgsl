@@ -0,0 +1,16 @@
+struct Packed {
+    a: f32,
+    b: f32,
+    c: vec2<f32>,
+}
+
+@group(0) @binding(0)
+var<storage, read> packed: Packed;
+
+@group(0) @binding(1)
+var<storage, read_write> sums: array<f32>;
+
+@compute @workgroup_size(1)
+fn main() {
+    sums[0] = packed.a + packed.b + packed.c.x + packed.c.y;
+}
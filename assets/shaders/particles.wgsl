@@ -0,0 +1,29 @@
+struct Transform {
+    translation: vec3<f32>,
+    scale: f32,
+}
+
+struct Particles {
+    center: vec3<f32>,
+    count: u32,
+    positions: array<vec3<f32>>,
+}
+
+@group(0) @binding(0)
+var<uniform> transform: Transform;
+
+@group(0) @binding(1)
+var<storage, read_write> particles: Particles;
+
+@compute @workgroup_size(1)
+fn main(@builtin(global_invocation_id) invocation_id: vec3<u32>) {
+    let i = invocation_id.x;
+    if i >= particles.count {
+        return;
+    }
+
+    particles.positions[i] = particles.positions[i] * transform.scale + transform.translation;
+    if i == 0u {
+        particles.center = particles.center * transform.scale + transform.translation;
+    }
+}
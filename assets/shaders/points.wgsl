@@ -0,0 +1,7 @@
+@group(0) @binding(0)
+var<storage, read_write> points: array<vec3<f32>>;
+
+@compute @workgroup_size(1)
+fn main(@builtin(global_invocation_id) invocation_id: vec3<u32>) {
+    points[invocation_id.x] += vec3(1.0, 2.0, 3.0);
+}
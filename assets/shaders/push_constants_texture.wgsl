@@ -0,0 +1,17 @@
+struct Params {
+    scale: f32,
+}
+
+var<push_constant> params: Params;
+
+@group(0) @binding(0)
+var input: texture_2d<f32>;
+
+@group(0) @binding(1)
+var<storage, read_write> values: array<f32>;
+
+@compute @workgroup_size(1)
+fn main(@builtin(global_invocation_id) invocation_id: vec3<u32>) {
+    let texel = textureLoad(input, vec2<u32>(invocation_id.x, 0u), 0);
+    values[invocation_id.x] = texel.r * params.scale;
+}
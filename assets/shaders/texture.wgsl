@@ -0,0 +1,15 @@
+@group(0) @binding(0)
+var input: texture_2d<f32>;
+
+@group(0) @binding(1)
+var input_sampler: sampler;
+
+@group(0) @binding(2)
+var output: texture_storage_2d<r32float, write>;
+
+@compute @workgroup_size(1)
+fn main(@builtin(global_invocation_id) invocation_id: vec3<u32>) {
+    let uv = (vec2<f32>(invocation_id.xy) + 0.5) / vec2<f32>(textureDimensions(input));
+    let texel = textureSampleLevel(input, input_sampler, uv, 0.0);
+    textureStore(output, invocation_id.xy, vec4<f32>(texel.r * 255.0 + 1.0, 0.0, 0.0, 1.0));
+}
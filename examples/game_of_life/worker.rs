@@ -58,8 +58,8 @@ impl ComputeWorker for GameOfLifeWorker {
             //
             // Create buffers
             .add_uniform(SETTINGS_BUFFER, &settings)
-            .add_storage(CELLS_IN_BUFFER, &initial_cell_data)
-            .add_storage(CELLS_OUT_BUFFER, &initial_cell_data)
+            .add_rw_storage(CELLS_IN_BUFFER, &initial_cell_data)
+            .add_rw_storage(CELLS_OUT_BUFFER, &initial_cell_data)
             .add_pass::<GameOfLifeShader>(
                 [
                     DIMENSIONS.0 / WORKGROUP_SIZE,
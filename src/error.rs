@@ -1,11 +1,111 @@
+use std::ops::Range;
+
+use bevy::render::render_resource::{encase, TextureFormat};
+use wgpu::Features;
+
 pub type Result<T> = std::result::Result<T, Error>;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum Error {
     BufferNotFound(String),
     StagingBufferNotFound(String),
+    /// The staging buffer is unmapped while the worker is working, so it can't be read.
+    StagingBufferNotMapped(String),
+    TextureNotFound(String),
+    /// `texture` can't have `format`, or its texels can't be copied, for `usage`.
+    UnsupportedTextureFormat {
+        texture: String,
+        format: TextureFormat,
+        usage: String,
+    },
     InvalidStep(String),
+    /// `buffer` (or what was copied of it) is `expected` bytes, but the operation needs `actual` bytes.
+    SizeMismatch {
+        buffer: String,
+        expected: u64,
+        actual: u64,
+    },
+    /// The `size` bytes at `offset` in `buffer` are not aligned to `alignment` bytes.
+    Misaligned {
+        buffer: String,
+        offset: u64,
+        size: u64,
+        alignment: u64,
+    },
+    /// The buffer wasn't created with `BufferUsages::INDIRECT`.
+    NotIndirect(String),
+    /// A copy step reads and writes the same buffer.
+    CopyToSelf {
+        src: String,
+        dst: String,
+    },
+    InvalidPushConstants {
+        shader: String,
+        size: u32,
+        max_size: u32,
+    },
+    /// `shader` needs `feature`, which the render device doesn't support.
+    MissingFeature {
+        shader: String,
+        feature: Features,
+    },
+    /// The bytes `range` of `buffer` are not within the `available` ones.
+    OutOfRange {
+        buffer: String,
+        range: Range<u64>,
+        available: Range<u64>,
+    },
+    /// encase could not encode or decode the contents of `buffer`.
+    Encase {
+        buffer: String,
+        error: encase::internal::Error,
+    },
     PipelinesEmpty,
+    ReflectionUnavailable(String),
+    UnmatchedGlobals {
+        shader: String,
+        globals: Vec<String>,
+    },
+    GlobalNotFound {
+        shader: String,
+        name: String,
+        group: u32,
+        binding: u32,
+    },
+    /// `name` is bound to `@group(group)`, but the pipeline layout of `shader` only has
+    /// `group_count` bind groups.
+    BindGroupOutOfRange {
+        shader: String,
+        name: String,
+        group: u32,
+        group_count: u32,
+    },
+    BindingMismatch {
+        shader: String,
+        name: String,
+        global: String,
+        resource: String,
+    },
+    BufferTooSmall {
+        shader: String,
+        name: String,
+        size: u64,
+        min_size: u64,
+    },
+    LayoutMismatch {
+        buffer: String,
+        rust_type: String,
+        /// Path of the WGSL member that doesn't match, e.g. `particles[1].velocity`.
+        field: String,
+        property: &'static str,
+        rust: u64,
+        wgsl: u64,
+    },
+    /// The shader of `shader` failed to load, so its pipeline will never be ready.
+    ShaderLoadFailed {
+        shader: String,
+        error: String,
+    },
     PipelineNotReady,
     EncoderIsNone,
 }
@@ -17,10 +117,131 @@ impl std::fmt::Display for Error {
         match self {
             Error::BufferNotFound(name) => write!(f, "Buffer {name} not found."),
             Error::StagingBufferNotFound(name) => write!(f, "Staging buffer {name} not found."),
+            Error::StagingBufferNotMapped(name) => write!(
+                f,
+                "Staging buffer {name} is not mapped, it can be read once the worker finished working."
+            ),
+            Error::TextureNotFound(name) => write!(f, "Texture {name} not found."),
+            Error::UnsupportedTextureFormat {
+                texture,
+                format,
+                usage,
+            } => write!(
+                f,
+                "Texture {texture} has format {format:?}, which can't be used {usage}."
+            ),
             Error::PipelinesEmpty => {
                 write!(f, "Missing pipelines. Have you added your shader plugins?")
             }
+            Error::ReflectionUnavailable(shader) => write!(
+                f,
+                "Shader {shader} cannot be reflected. Only WGSL shaders support automatic bindings and workgroup counts."
+            ),
+            Error::UnmatchedGlobals { shader, globals } => write!(
+                f,
+                "Shader {shader} declares globals with no buffer, texture or sampler of the same name: {}.",
+                globals.join(", ")
+            ),
+            Error::GlobalNotFound {
+                shader,
+                name,
+                group,
+                binding,
+            } => write!(
+                f,
+                "Shader {shader} has no global at @group({group}) @binding({binding}) to bind {name} to."
+            ),
+            Error::BindGroupOutOfRange {
+                shader,
+                name,
+                group,
+                group_count,
+            } => write!(
+                f,
+                "Shader {shader} has {group_count} bind groups, {name} can't be bound to @group({group})."
+            ),
+            Error::BindingMismatch {
+                shader,
+                name,
+                global,
+                resource,
+            } => write!(
+                f,
+                "Shader {shader} expects a {global} for {name}, but {name} is a {resource}."
+            ),
+            Error::BufferTooSmall {
+                shader,
+                name,
+                size,
+                min_size,
+            } => write!(
+                f,
+                "Buffer {name} is {size} bytes, but shader {shader} needs at least {min_size} bytes."
+            ),
+            Error::LayoutMismatch {
+                buffer,
+                rust_type,
+                field,
+                property,
+                rust,
+                wgsl,
+            } => write!(
+                f,
+                "Layout of {rust_type} doesn't match the WGSL type of {buffer}: {property} of `{field}` is {rust} in Rust, but {wgsl} in WGSL."
+            ),
             Error::InvalidStep(step) => write!(f, "Invalid step `{step}`."),
+            Error::SizeMismatch {
+                buffer,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "Buffer {buffer} is {expected} bytes, but {actual} bytes are accessed."
+            ),
+            Error::Misaligned {
+                buffer,
+                offset,
+                size,
+                alignment,
+            } => write!(
+                f,
+                "{size} bytes at offset {offset} of buffer {buffer} are not aligned to {alignment} bytes."
+            ),
+            Error::NotIndirect(name) => write!(
+                f,
+                "Buffer {name} can't hold indirect dispatch arguments, create it with `add_rw_storage` or `add_staging`."
+            ),
+            Error::CopyToSelf { src, dst } => write!(
+                f,
+                "Can't copy {src} into {dst}, they are the same buffer."
+            ),
+            Error::InvalidPushConstants {
+                shader,
+                size,
+                max_size,
+            } => write!(
+                f,
+                "Push constants of {size} bytes don't fit {shader}: they must be a multiple of 4 bytes, and at most {max_size} bytes."
+            ),
+            Error::MissingFeature { shader, feature } => write!(
+                f,
+                "{shader} needs {feature:?}, but the render device doesn't support it. \
+                Request it in the `WgpuSettings` of the `RenderPlugin`."
+            ),
+            Error::OutOfRange {
+                buffer,
+                range,
+                available,
+            } => write!(
+                f,
+                "Bytes {range:?} of buffer {buffer} are out of the available bytes {available:?}."
+            ),
+            Error::Encase { buffer, error } => {
+                write!(f, "Could not encode or decode buffer {buffer}: {error}.")
+            }
+            Error::ShaderLoadFailed { shader, error } => {
+                write!(f, "Shader {shader} failed to load: {error}")
+            }
             Error::PipelineNotReady => write!(f, "Pipeline isn't ready yet."),
             Error::EncoderIsNone => write!(f, "The command encoder hasn't been initialized."),
         }
@@ -0,0 +1,82 @@
+//! Typed handles to the buffers of an [`AppComputeWorker`](crate::worker::AppComputeWorker).
+//!
+//! A [`BufferHandle<T, K>`] is returned by the `add_*_handle` methods of
+//! [`AppComputeWorkerBuilder`](crate::worker_builder::AppComputeWorkerBuilder). It remembers
+//! the element type `T` of the buffer and how it was declared (`K`), so that reading a buffer
+//! with the wrong type, or reading a buffer that has no staging buffer, doesn't compile.
+
+use std::{fmt, marker::PhantomData};
+
+/// Marker for buffers declared with `add_uniform_handle`.
+pub struct Uniform;
+
+/// Marker for buffers declared with `add_storage_handle`. They are read only in shaders.
+pub struct Storage;
+
+/// Marker for buffers declared with `add_rw_storage_handle`.
+pub struct RwStorage;
+
+/// Marker for buffers declared with `add_staging_handle`. Only these can be read back.
+pub struct Staging;
+
+/// How the buffer behind a [`BufferHandle`] was declared.
+pub trait HandleKind: sealed::Sealed + Send + Sync + 'static {}
+
+impl HandleKind for Uniform {}
+impl HandleKind for Storage {}
+impl HandleKind for RwStorage {}
+impl HandleKind for Staging {}
+
+/// Kinds of buffers that hold a slice of `T`, written with
+/// [`AppComputeWorker::write_handle`](crate::worker::AppComputeWorker::write_handle). Uniform
+/// buffers hold a single `T` instead.
+pub trait ArrayKind: HandleKind {}
+
+impl ArrayKind for Storage {}
+impl ArrayKind for RwStorage {}
+impl ArrayKind for Staging {}
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for super::Uniform {}
+    impl Sealed for super::Storage {}
+    impl Sealed for super::RwStorage {}
+    impl Sealed for super::Staging {}
+}
+
+/// A buffer of `T` elements, declared as `K`.
+pub struct BufferHandle<T, K: HandleKind> {
+    name: String,
+    _phantom: PhantomData<fn() -> (T, K)>,
+}
+
+impl<T, K: HandleKind> BufferHandle<T, K> {
+    pub(crate) fn new(name: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Name of the buffer, to use it in passes or with the `&str` based methods.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl<T, K: HandleKind> Clone for BufferHandle<T, K> {
+    fn clone(&self) -> Self {
+        Self::new(&self.name)
+    }
+}
+
+impl<T, K: HandleKind> fmt::Debug for BufferHandle<T, K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BufferHandle")
+            .field("name", &self.name)
+            .field("type", &std::any::type_name::<T>())
+            .field("kind", &std::any::type_name::<K>())
+            .finish()
+    }
+}
@@ -0,0 +1,91 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll, Waker},
+};
+
+use parking_lot::Mutex;
+
+use crate::{error::Result, traits::ComputeWorker, worker::AppComputeWorker};
+
+/// The result of a job submitted with [`AppComputeWorker::submit_job`].
+///
+/// It can be awaited, e.g. from a bevy `Task`, or polled from a system with [`Self::try_take`].
+pub struct ComputeJob<T> {
+    slot: Arc<Mutex<JobSlot<T>>>,
+}
+
+struct JobSlot<T> {
+    result: Option<Result<T>>,
+    waker: Option<Waker>,
+}
+
+impl<T> ComputeJob<T> {
+    /// Check if the job is done, and its result can be taken.
+    pub fn is_finished(&self) -> bool {
+        self.slot.lock().result.is_some()
+    }
+
+    /// Take the result of the job if it is done.
+    ///
+    /// Returns an error if the inputs of the job could not be written.
+    pub fn try_take(&mut self) -> Option<Result<T>> {
+        self.slot.lock().result.take()
+    }
+}
+
+impl<T> Future for ComputeJob<T> {
+    type Output = Result<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut slot = self.slot.lock();
+        match slot.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                slot.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+type CompleteFn<W> = dyn FnOnce(&AppComputeWorker<W>, Result<()>) + Send + Sync;
+
+/// A job waiting in the queue of a worker, or being run.
+pub(crate) struct PendingJob<W: ComputeWorker> {
+    /// Bytes written to the start of each buffer before the job runs.
+    pub(crate) inputs: Vec<(String, Vec<u8>)>,
+    /// Reads the output of the job from the worker and resolves its [`ComputeJob`], or fails it.
+    pub(crate) complete: Box<CompleteFn<W>>,
+}
+
+impl<W: ComputeWorker> PendingJob<W> {
+    pub(crate) fn new<T: Send + 'static>(
+        inputs: Vec<(String, Vec<u8>)>,
+        output: impl FnOnce(&AppComputeWorker<W>) -> T + Send + Sync + 'static,
+    ) -> (Self, ComputeJob<T>) {
+        let slot = Arc::new(Mutex::new(JobSlot {
+            result: None,
+            waker: None,
+        }));
+
+        let job_slot = slot.clone();
+        let complete = move |worker: &AppComputeWorker<W>, written: Result<()>| {
+            let result = written.map(|_| output(worker));
+
+            let mut slot = job_slot.lock();
+            slot.result = Some(result);
+            if let Some(waker) = slot.waker.take() {
+                waker.wake();
+            }
+        };
+
+        let job = Self {
+            inputs,
+            complete: Box::new(complete),
+        };
+
+        (job, ComputeJob { slot })
+    }
+}
@@ -0,0 +1,214 @@
+use std::ops::Range;
+
+use bevy::render::render_resource::{
+    encase::internal::{BufferMut, WriteInto, Writer},
+    ShaderType,
+};
+use naga::{proc::Layouter, ArraySize, Handle, Module, Type, TypeInner};
+
+use crate::error::{Error, Result};
+
+/// Maximum number of writes and WGSL members compared. Arrays repeat the layout of their first
+/// elements, so larger buffers are only compared up to this point.
+const MAX_COMPARED: usize = 1024;
+
+/// Layout of a Rust [`ShaderType`], as encase writes it into a buffer.
+///
+/// It is recorded from the writes of the scalars, vectors and matrix columns of a value, so
+/// nested structs and array elements are covered without relying on encase internals.
+#[derive(Clone, Debug)]
+pub(crate) struct RustLayout {
+    pub(crate) type_name: &'static str,
+    /// Byte ranges written by encase, in order.
+    writes: Vec<Range<u64>>,
+    /// End of the last byte written, even past the recorded writes.
+    end: u64,
+    /// Whether `writes` stops before the end of the value.
+    truncated: bool,
+}
+
+impl RustLayout {
+    pub(crate) fn of<T: ?Sized + ShaderType + WriteInto>(value: &T) -> Self {
+        let mut recorder = WriteRecorder::default();
+        if let Ok(mut writer) = Writer::new(value, &mut recorder, 0) {
+            value.write_into(&mut writer);
+        }
+
+        Self {
+            type_name: std::any::type_name::<T>(),
+            truncated: recorder.count > recorder.writes.len(),
+            writes: recorder.writes,
+            end: recorder.end,
+        }
+    }
+
+    /// Compare this layout with the WGSL type `ty` the buffer `buffer` is bound to.
+    ///
+    /// Every Rust write must start at the offset of a WGSL scalar, vector or matrix column, and
+    /// cover whole members only. This compares the offsets and sizes of nested members and the
+    /// stride of arrays. Runtime-sized WGSL arrays are given as many elements as the Rust value.
+    pub(crate) fn check(&self, buffer: &str, module: &Module, ty: Handle<Type>) -> Result<()> {
+        let mut layouter = Layouter::default();
+        // Modules that can't be laid out are rejected by naga's validation
+        if layouter.update(module.to_ctx()).is_err() {
+            return Ok(());
+        }
+
+        let mismatch =
+            |field: &str, property: &'static str, rust: u64, wgsl: u64| Error::LayoutMismatch {
+                buffer: buffer.to_owned(),
+                rust_type: self.type_name.to_owned(),
+                field: field.to_owned(),
+                property,
+                rust,
+                wgsl,
+            };
+
+        let mut members = Vec::new();
+        let wgsl = WgslLayout {
+            module,
+            layouter: &layouter,
+            end: self.end,
+        };
+        wgsl.collect(ty, 0, buffer.to_owned(), &mut members);
+        let members_truncated = members.len() >= MAX_COMPARED;
+
+        let mut writes = self.writes.iter();
+        let mut write = writes.next();
+        let mut cursor = write.map_or(0, |write| write.start);
+
+        for member in &members {
+            let Some(current) = write else {
+                if self.truncated {
+                    return Ok(());
+                }
+                return Err(mismatch(buffer, "size", self.end, member.range.end));
+            };
+
+            if member.range.start != cursor {
+                return Err(mismatch(&member.path, "offset", cursor, member.range.start));
+            }
+            if member.range.end > current.end {
+                return Err(mismatch(
+                    &member.path,
+                    "size",
+                    current.end - cursor,
+                    member.range.end - member.range.start,
+                ));
+            }
+
+            cursor = member.range.end;
+            if cursor == current.end {
+                write = writes.next();
+                cursor = write.map_or(cursor, |write| write.start);
+            }
+        }
+
+        if write.is_some() && !members_truncated {
+            let wgsl_end = members.last().map_or(0, |member| member.range.end);
+            return Err(mismatch(buffer, "size", self.end, wgsl_end));
+        }
+
+        Ok(())
+    }
+}
+
+/// [`BufferMut`] that records where encase writes instead of storing the bytes.
+#[derive(Default)]
+struct WriteRecorder {
+    writes: Vec<Range<u64>>,
+    count: usize,
+    end: u64,
+}
+
+impl WriteRecorder {
+    fn record(&mut self, offset: usize, len: usize) {
+        let range = offset as u64..(offset + len) as u64;
+        self.end = self.end.max(range.end);
+        self.count += 1;
+        if self.writes.len() < MAX_COMPARED {
+            self.writes.push(range);
+        }
+    }
+}
+
+impl BufferMut for WriteRecorder {
+    fn capacity(&self) -> usize {
+        usize::MAX
+    }
+
+    fn write<const N: usize>(&mut self, offset: usize, _val: &[u8; N]) {
+        self.record(offset, N);
+    }
+
+    fn write_slice(&mut self, offset: usize, val: &[u8]) {
+        self.record(offset, val.len());
+    }
+}
+
+/// A scalar, vector or matrix column of a WGSL type.
+struct WgslMember {
+    path: String,
+    range: Range<u64>,
+}
+
+struct WgslLayout<'a> {
+    module: &'a Module,
+    layouter: &'a Layouter,
+    /// End of the Rust data, which sets the length of runtime-sized arrays.
+    end: u64,
+}
+
+impl WgslLayout<'_> {
+    fn collect(&self, ty: Handle<Type>, offset: u64, path: String, out: &mut Vec<WgslMember>) {
+        if out.len() >= MAX_COMPARED {
+            return;
+        }
+
+        let size = self.layouter[ty].size as u64;
+        match &self.module.types[ty].inner {
+            TypeInner::Struct { members, .. } => {
+                for (index, member) in members.iter().enumerate() {
+                    let name = match &member.name {
+                        Some(name) => format!("{path}.{name}"),
+                        None => format!("{path}.{index}"),
+                    };
+                    self.collect(member.ty, offset + member.offset as u64, name, out);
+                }
+            }
+            TypeInner::Array { base, size, stride } => {
+                let stride = *stride as u64;
+                let len = match size {
+                    ArraySize::Constant(len) => len.get() as u64,
+                    ArraySize::Dynamic => self.end.saturating_sub(offset).div_ceil(stride),
+                };
+                for i in 0..len {
+                    if out.len() >= MAX_COMPARED {
+                        break;
+                    }
+                    self.collect(*base, offset + i * stride, format!("{path}[{i}]"), out);
+                }
+            }
+            TypeInner::Matrix {
+                columns,
+                rows,
+                scalar,
+            } => {
+                let columns = *columns as u64;
+                let stride = size / columns;
+                let column_size = *rows as u64 * scalar.width as u64;
+                for i in 0..columns {
+                    let start = offset + i * stride;
+                    out.push(WgslMember {
+                        path: format!("{path}[{i}]"),
+                        range: start..start + column_size,
+                    });
+                }
+            }
+            _ => out.push(WgslMember {
+                path,
+                range: offset..offset + size,
+            }),
+        }
+    }
+}
@@ -5,21 +5,31 @@ use bevy::prelude::{AssetEvent, Assets, EventReader, Res, ResMut, Shader};
 use pipeline_cache::AppPipelineCache;
 
 mod error;
+pub mod handle;
+mod job;
+mod layout;
 mod pipeline_cache;
 mod plugin;
+mod reflection;
+mod run_conditions;
 mod traits;
 mod worker;
 mod worker_builder;
 
+pub use error::Error;
+
 /// Helper module to import most used elements.
 pub mod prelude {
     pub use crate::{
+        handle::{ArrayKind, BufferHandle, HandleKind, RwStorage, Staging, Storage, Uniform},
+        job::ComputeJob,
         plugin::{
             AppComputePlugin, AppComputeWorkerPlugin, BevyEasyComputePostUpdateSet,
             BevyEasyComputeSet,
         },
+        run_conditions::{worker_idle, worker_ready},
         traits::{ComputeShader, ComputeWorker},
-        worker::AppComputeWorker,
+        worker::{AppComputeWorker, ComputeFinished, StepContext, StepId},
         worker_builder::AppComputeWorkerBuilder,
     };
 
@@ -7,6 +7,7 @@
 use std::borrow::Cow;
 use std::iter::FusedIterator;
 use std::mem;
+use std::sync::Arc;
 
 use bevy::prelude::*;
 use bevy::render::render_resource::{
@@ -25,6 +26,8 @@ use wgpu::{
     PushConstantRange, ShaderModuleDescriptor,
 };
 
+use crate::reflection::ShaderReflection;
+
 pub struct CachedAppPipeline {
     state: CachedPipelineState,
     descriptor: Box<ComputePipelineDescriptor>,
@@ -44,10 +47,18 @@ impl CachedAppComputePipelineId {
     }
 }
 
+/// A shader module, along with the naga module it was created from when there is one
+/// (SPIR-V shaders are passed through to wgpu as is).
+#[derive(Clone)]
+struct ProcessedShader {
+    module: ErasedShaderModule,
+    naga: Option<Arc<naga::Module>>,
+}
+
 #[derive(Default)]
 struct ShaderData {
     pipelines: HashSet<CachedAppComputePipelineId>,
-    processed_shaders: HashMap<Vec<ShaderDefVal>, ErasedShaderModule>,
+    processed_shaders: HashMap<Vec<ShaderDefVal>, ProcessedShader>,
     resolved_imports: HashMap<ShaderImport, AssetId<Shader>>,
     dependents: HashSet<AssetId<Shader>>,
 }
@@ -140,7 +151,7 @@ impl ShaderCache {
         pipeline: CachedAppComputePipelineId,
         shader_asset_id: &AssetId<Shader>,
         shader_defs: &[ShaderDefVal],
-    ) -> Result<ErasedShaderModule, PipelineCacheError> {
+    ) -> Result<ProcessedShader, PipelineCacheError> {
         let shader = self
             .shaders
             .get(shader_asset_id)
@@ -181,9 +192,9 @@ impl ShaderCache {
                     "processing shader {:?}, with shader defs {:?}",
                     shader_asset_id, shader_defs
                 );
-                let shader_source = match &shader.source {
+                let (shader_source, naga_module) = match &shader.source {
                     #[cfg(feature = "shader_format_spirv")]
-                    Source::SpirV(data) => make_spirv(data),
+                    Source::SpirV(data) => (make_spirv(data), None),
                     #[cfg(not(feature = "shader_format_spirv"))]
                     Source::SpirV(_) => {
                         unimplemented!(
@@ -222,7 +233,11 @@ impl ShaderCache {
                             },
                         )?;
 
-                        wgpu::ShaderSource::Naga(Cow::Owned(naga))
+                        let naga = Arc::new(naga);
+                        (
+                            wgpu::ShaderSource::Naga(Cow::Owned(naga.as_ref().clone())),
+                            Some(naga),
+                        )
                     }
                 };
 
@@ -247,7 +262,10 @@ impl ShaderCache {
                     return Err(PipelineCacheError::CreateShaderModule(description));
                 }
 
-                entry.insert(ErasedShaderModule::new(shader_module))
+                entry.insert(ProcessedShader {
+                    module: ErasedShaderModule::new(shader_module),
+                    naga: naga_module,
+                })
             }
         };
 
@@ -355,6 +373,7 @@ pub struct AppPipelineCache {
     device: RenderDevice,
     pipelines: Vec<CachedAppPipeline>,
     waiting_pipelines: HashSet<CachedAppComputePipelineId>,
+    reflections: HashMap<CachedAppComputePipelineId, Arc<ShaderReflection>>,
     new_pipelines: Mutex<Vec<CachedAppPipeline>>,
 }
 
@@ -366,6 +385,7 @@ impl AppPipelineCache {
             layout_cache: default(),
             waiting_pipelines: default(),
             new_pipelines: default(),
+            reflections: default(),
             pipelines: default(),
         }
     }
@@ -433,33 +453,63 @@ impl AppPipelineCache {
         id: CachedAppComputePipelineId,
         descriptor: &ComputePipelineDescriptor,
     ) -> CachedPipelineState {
-        let layout = if descriptor.layout.is_empty() && descriptor.push_constant_ranges.is_empty() {
-            None
-        } else {
-            Some(self.layout_cache.get(
-                &self.device,
-                &descriptor.layout,
-                descriptor.push_constant_ranges.to_vec(),
-            ))
-        };
-
-        let compute_module = match self.shader_cache.get(
+        let processed_shader = match self.shader_cache.get(
             &self.device,
             id,
             &descriptor.shader.id(),
             &descriptor.shader_defs,
         ) {
-            Ok(module) => module,
+            Ok(processed_shader) => processed_shader,
             Err(err) => {
                 return CachedPipelineState::Err(err);
             }
         };
 
+        match processed_shader.naga {
+            Some(naga) => {
+                let reflection = ShaderReflection::new(naga, &descriptor.entry_point);
+                self.reflections.insert(id, Arc::new(reflection));
+            }
+            None => {
+                self.reflections.remove(&id);
+            }
+        }
+
+        // wgpu can't derive a layout with push constants, so derive the bind groups from the shader.
+        let bind_group_layouts = match self.reflections.get(&id) {
+            Some(reflection)
+                if descriptor.layout.is_empty() && !descriptor.push_constant_ranges.is_empty() =>
+            {
+                let entries = reflection.bind_group_layout_entries();
+                let group_count = entries.keys().next_back().map_or(0, |group| group + 1);
+                (0..group_count)
+                    .map(|group| {
+                        self.device.create_bind_group_layout(
+                            None,
+                            entries.get(&group).map_or(&[], Vec::as_slice),
+                        )
+                    })
+                    .collect()
+            }
+            _ => descriptor.layout.clone(),
+        };
+
+        let layout = if bind_group_layouts.is_empty() && descriptor.push_constant_ranges.is_empty()
+        {
+            None
+        } else {
+            Some(self.layout_cache.get(
+                &self.device,
+                &bind_group_layouts,
+                descriptor.push_constant_ranges.to_vec(),
+            ))
+        };
+
         let descriptor = wgpu::ComputePipelineDescriptor {
             compilation_options: PipelineCompilationOptions::default(), // changed
             label: descriptor.label.as_deref(),
             layout,
-            module: &compute_module,
+            module: &processed_shader.module,
             entry_point: descriptor.entry_point.as_ref(),
         };
 
@@ -483,6 +533,29 @@ impl AppPipelineCache {
         }
     }
 
+    /// Shader of a compute pipeline, once it left the queue.
+    #[inline]
+    pub(crate) fn get_compute_pipeline_shader(
+        &self,
+        id: CachedAppComputePipelineId,
+    ) -> Option<&Handle<Shader>> {
+        self.pipelines
+            .get(id.0)
+            .map(|pipeline| &pipeline.descriptor.shader)
+    }
+
+    /// Reflection of the shader of a compute pipeline, available once the pipeline is created.
+    ///
+    /// It is `None` for SPIR-V shaders.
+    #[inline]
+    pub(crate) fn get_compute_pipeline_reflection(
+        &self,
+        id: CachedAppComputePipelineId,
+    ) -> Option<&Arc<ShaderReflection>> {
+        self.get_compute_pipeline(id)?;
+        self.reflections.get(&id)
+    }
+
     pub fn set_shader(&mut self, shader_asset_id: &AssetId<Shader>, shader: &Shader) {
         let pipelines_to_queue = self
             .shader_cache
@@ -1,10 +1,19 @@
-use std::marker::PhantomData;
+use std::{marker::PhantomData, time::Duration};
 
-use bevy::{prelude::*, render::renderer::RenderDevice};
+use bevy::{
+    ecs::schedule::{BoxedCondition, InternedScheduleLabel, ScheduleLabel},
+    prelude::*,
+    render::renderer::RenderDevice,
+};
+use parking_lot::Mutex;
 
 use crate::{
-    extract_shaders, pipeline_cache::AppPipelineCache, process_pipeline_queue_system,
-    traits::ComputeWorker, worker::AppComputeWorker,
+    error::Error,
+    extract_shaders,
+    pipeline_cache::AppPipelineCache,
+    process_pipeline_queue_system,
+    traits::ComputeWorker,
+    worker::{AppComputeWorker, ComputeFinished, PIPELINE_TIMEOUT},
 };
 
 /// The main plugin. Always include it if you want to use `bevy_easy_compute`
@@ -45,23 +54,105 @@ pub enum BevyEasyComputePostUpdateSet {
 }
 
 /// Plugin to initialise your [`AppComputeWorker<W>`] structs.
+///
+/// By default, the worker runs in `PostUpdate`. Use [`Self::in_schedule`] and [`Self::run_if`]
+/// to change when it runs, and [`Self::precompute`] to run it once before the first frame.
 pub struct AppComputeWorkerPlugin<W: ComputeWorker> {
+    schedule: InternedScheduleLabel,
+    /// Taken when the plugin is finished, as boxed conditions can't be cloned.
+    conditions: Mutex<Vec<BoxedCondition>>,
+    precompute: bool,
+    precompute_timeout: Duration,
     _phantom: PhantomData<W>,
 }
 
 impl<W: ComputeWorker> Default for AppComputeWorkerPlugin<W> {
     fn default() -> Self {
         Self {
+            schedule: PostUpdate.intern(),
+            conditions: Mutex::default(),
+            precompute: false,
+            precompute_timeout: PIPELINE_TIMEOUT,
             _phantom: Default::default(),
         }
     }
 }
 
+impl<W: ComputeWorker> AppComputeWorkerPlugin<W> {
+    /// Run the worker in `schedule` instead of `PostUpdate`, e.g. `FixedUpdate` to step it
+    /// along a fixed timestep simulation.
+    ///
+    /// Pipelines are still extracted in `Update`.
+    pub fn in_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.schedule = schedule.intern();
+        self
+    }
+
+    /// Only run the worker when `condition` is true, e.g. `in_state(GameState::Playing)`.
+    ///
+    /// Can be called several times, the worker then runs when all conditions are true.
+    pub fn run_if<M>(mut self, condition: impl Condition<M>) -> Self {
+        self.conditions
+            .get_mut()
+            .push(Box::new(IntoSystem::into_system(condition)));
+        self
+    }
+
+    /// Run the worker once when the plugin is finished, so its results can be read by the systems
+    /// in `Startup` and `PostStartup`.
+    ///
+    /// This blocks until the shaders of the worker are loaded and its pipelines are compiled, and
+    /// panics if a shader fails to load, or if they aren't ready after 30 seconds (see
+    /// [`Self::precompute_timeout`]).
+    ///
+    /// Not supported with the `webgl` feature: browsers only load shaders between frames, so
+    /// `finish` panics instead of waiting for them.
+    pub fn precompute(mut self) -> Self {
+        self.precompute = true;
+        self
+    }
+
+    /// How long [`Self::precompute`] waits for the shaders of the worker to load and compile.
+    pub fn precompute_timeout(mut self, timeout: Duration) -> Self {
+        self.precompute_timeout = timeout;
+        self
+    }
+}
+
+/// Load the shaders of `worker` and compile its pipelines, then run it to completion.
+fn precompute<W: ComputeWorker>(
+    world: &mut World,
+    worker: &mut AppComputeWorker<W>,
+    timeout: Duration,
+) {
+    match worker.run_blocking_with_timeout(world, timeout) {
+        Ok(()) => {}
+        Err(Error::PipelineNotReady) => panic!(
+            "Pipelines of {} weren't ready after {timeout:?}, check that its shaders compile.",
+            std::any::type_name::<W>()
+        ),
+        Err(err) => panic!("{err}"),
+    }
+}
+
 impl<W: ComputeWorker> Plugin for AppComputeWorkerPlugin<W> {
     fn build(&self, _app: &mut App) {}
 
     fn finish(&self, app: &mut App) {
-        let worker = W::build(app.world_mut());
+        // Registered first, so precomputing can send the event of its run
+        app.add_event::<ComputeFinished<W>>();
+
+        let mut worker = W::build(app.world_mut());
+        if self.precompute {
+            precompute(app.world_mut(), &mut worker, self.precompute_timeout);
+        }
+
+        let mut systems = (AppComputeWorker::<W>::unmap_all, AppComputeWorker::<W>::run)
+            .in_set(BevyEasyComputePostUpdateSet::ExecuteCompute)
+            .chain();
+        for condition in self.conditions.lock().drain(..) {
+            systems.run_if_dyn(condition);
+        }
 
         app.insert_resource(worker)
             .add_systems(
@@ -70,11 +161,6 @@ impl<W: ComputeWorker> Plugin for AppComputeWorkerPlugin<W> {
                     .in_set(BevyEasyComputeSet::ExtractPipelines)
                     .after(process_pipeline_queue_system),
             )
-            .add_systems(
-                PostUpdate,
-                (AppComputeWorker::<W>::unmap_all, AppComputeWorker::<W>::run)
-                    .in_set(BevyEasyComputePostUpdateSet::ExecuteCompute)
-                    .chain(),
-            );
+            .add_systems(self.schedule, systems);
     }
 }
@@ -0,0 +1,293 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use naga::{
+    valid::{Capabilities, ValidationFlags, Validator},
+    AddressSpace, Handle, ImageClass, ImageDimension, Module, ScalarKind, ShaderStage,
+    StorageAccess, StorageFormat, Type, TypeInner,
+};
+use wgpu::{
+    BindGroupLayoutEntry, BindingType, BufferBindingType, SamplerBindingType, ShaderStages,
+    StorageTextureAccess, TextureFormat, TextureSampleType, TextureViewDimension,
+};
+
+/// Marker naga_oil inserts in the names of items coming from imported modules.
+const NAGA_OIL_DECORATION: &str = "X_naga_oil_mod_X";
+
+/// A resource bound global variable of a compute shader.
+#[derive(Clone, Debug)]
+pub(crate) struct ReflectedGlobal {
+    /// Name as declared in WGSL, without any naga_oil module decoration.
+    pub(crate) name: String,
+    pub(crate) group: u32,
+    pub(crate) binding: u32,
+    pub(crate) kind: GlobalKind,
+    /// Minimum size in bytes of a buffer bound to this global,
+    /// runtime-sized arrays counting as a single element.
+    pub(crate) min_binding_size: u64,
+    pub(crate) ty: Handle<Type>,
+    /// Whether the entry point samples this texture with a sampler, so it must be filterable.
+    pub(crate) sampled: bool,
+}
+
+/// What kind of resource a global variable expects, from its address space and type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum GlobalKind {
+    Uniform,
+    Storage { read_only: bool },
+    StorageTexture { access: StorageAccess },
+    SampledTexture,
+    Sampler,
+    Other,
+}
+
+impl std::fmt::Display for GlobalKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GlobalKind::Uniform => write!(f, "var<uniform>"),
+            GlobalKind::Storage { read_only: true } => write!(f, "var<storage, read>"),
+            GlobalKind::Storage { read_only: false } => write!(f, "var<storage, read_write>"),
+            GlobalKind::StorageTexture { access } => write!(f, "storage texture ({access:?})"),
+            GlobalKind::SampledTexture => write!(f, "texture"),
+            GlobalKind::Sampler => write!(f, "sampler"),
+            GlobalKind::Other => write!(f, "unsupported global"),
+        }
+    }
+}
+
+/// Reflection data of a compute shader entry point, taken from its composed naga module.
+#[derive(Debug)]
+pub(crate) struct ShaderReflection {
+    pub(crate) module: Arc<Module>,
+    /// Bound globals used by the entry point, sorted by group and binding.
+    pub(crate) globals: Vec<ReflectedGlobal>,
+    /// `@workgroup_size` of the entry point.
+    pub(crate) workgroup_size: Option<[u32; 3]>,
+}
+
+impl ShaderReflection {
+    pub(crate) fn new(module: Arc<Module>, entry_point: &str) -> Self {
+        let info = Validator::new(ValidationFlags::all(), Capabilities::all())
+            .validate(&module)
+            .ok();
+        let entry_point_index = module
+            .entry_points
+            .iter()
+            .position(|ep| ep.name == entry_point && ep.stage == ShaderStage::Compute);
+
+        let mut globals = module
+            .global_variables
+            .iter()
+            .filter_map(|(handle, global)| {
+                let binding = global.binding.as_ref()?;
+
+                // Globals unused by the entry point aren't part of the derived pipeline layout
+                if let (Some(info), Some(index)) = (&info, entry_point_index) {
+                    if info.get_entry_point(index)[handle].is_empty() {
+                        return None;
+                    }
+                }
+
+                // Without validation info, assume the texture is sampled like wgpu does
+                let sampled = match (&info, entry_point_index) {
+                    (Some(info), Some(index)) => info
+                        .get_entry_point(index)
+                        .sampling_set
+                        .iter()
+                        .any(|key| key.image == handle),
+                    _ => true,
+                };
+
+                let inner = &module.types[global.ty].inner;
+                let kind = match (global.space, inner) {
+                    (AddressSpace::Uniform, _) => GlobalKind::Uniform,
+                    (AddressSpace::Storage { access }, _) => GlobalKind::Storage {
+                        read_only: !access.contains(StorageAccess::STORE),
+                    },
+                    (
+                        AddressSpace::Handle,
+                        TypeInner::Image {
+                            class: ImageClass::Storage { access, .. },
+                            ..
+                        },
+                    ) => GlobalKind::StorageTexture { access: *access },
+                    (AddressSpace::Handle, TypeInner::Image { .. }) => GlobalKind::SampledTexture,
+                    (AddressSpace::Handle, TypeInner::Sampler { .. }) => GlobalKind::Sampler,
+                    _ => GlobalKind::Other,
+                };
+
+                Some(ReflectedGlobal {
+                    name: undecorated_name(global.name.as_deref().unwrap_or_default()),
+                    group: binding.group,
+                    binding: binding.binding,
+                    kind,
+                    min_binding_size: inner.size(module.to_ctx()) as u64,
+                    ty: global.ty,
+                    sampled,
+                })
+            })
+            .collect::<Vec<_>>();
+        globals.sort_by_key(|global| (global.group, global.binding));
+
+        let workgroup_size =
+            entry_point_index.map(|index| module.entry_points[index].workgroup_size);
+
+        Self {
+            module,
+            globals,
+            workgroup_size,
+        }
+    }
+
+    /// Bind group layout entries of the globals, by group, for pipelines that can't derive their
+    /// layout (e.g. when using push constants).
+    ///
+    /// Float textures are only filterable if the entry point samples them, so that unfilterable
+    /// formats like `R32Float` can be bound to the others. Samplers are assumed to be filtering.
+    pub(crate) fn bind_group_layout_entries(&self) -> BTreeMap<u32, Vec<BindGroupLayoutEntry>> {
+        let mut entries: BTreeMap<u32, Vec<BindGroupLayoutEntry>> = BTreeMap::new();
+
+        for global in &self.globals {
+            let Some(ty) = self.binding_type(global) else {
+                continue;
+            };
+
+            entries
+                .entry(global.group)
+                .or_default()
+                .push(BindGroupLayoutEntry {
+                    binding: global.binding,
+                    visibility: ShaderStages::COMPUTE,
+                    ty,
+                    count: None,
+                });
+        }
+
+        entries
+    }
+
+    fn binding_type(&self, global: &ReflectedGlobal) -> Option<BindingType> {
+        let buffer = |ty| BindingType::Buffer {
+            ty,
+            has_dynamic_offset: false,
+            min_binding_size: None,
+        };
+
+        let binding_type = match (global.kind, &self.module.types[global.ty].inner) {
+            (GlobalKind::Uniform, _) => buffer(BufferBindingType::Uniform),
+            (GlobalKind::Storage { read_only }, _) => {
+                buffer(BufferBindingType::Storage { read_only })
+            }
+            (
+                _,
+                TypeInner::Image {
+                    dim,
+                    arrayed,
+                    class,
+                },
+            ) => {
+                let view_dimension = view_dimension(*dim, *arrayed);
+                match class {
+                    ImageClass::Sampled { kind, multi } => BindingType::Texture {
+                        sample_type: match kind {
+                            ScalarKind::Sint => TextureSampleType::Sint,
+                            ScalarKind::Uint => TextureSampleType::Uint,
+                            _ => TextureSampleType::Float {
+                                filterable: global.sampled,
+                            },
+                        },
+                        view_dimension,
+                        multisampled: *multi,
+                    },
+                    ImageClass::Depth { multi } => BindingType::Texture {
+                        sample_type: TextureSampleType::Depth,
+                        view_dimension,
+                        multisampled: *multi,
+                    },
+                    ImageClass::Storage { format, access } => BindingType::StorageTexture {
+                        access: match (
+                            access.contains(StorageAccess::LOAD),
+                            access.contains(StorageAccess::STORE),
+                        ) {
+                            (true, true) => StorageTextureAccess::ReadWrite,
+                            (true, false) => StorageTextureAccess::ReadOnly,
+                            _ => StorageTextureAccess::WriteOnly,
+                        },
+                        format: texture_format(*format),
+                        view_dimension,
+                    },
+                }
+            }
+            (_, TypeInner::Sampler { comparison }) => BindingType::Sampler(if *comparison {
+                SamplerBindingType::Comparison
+            } else {
+                SamplerBindingType::Filtering
+            }),
+            _ => return None,
+        };
+
+        Some(binding_type)
+    }
+}
+
+fn view_dimension(dim: ImageDimension, arrayed: bool) -> TextureViewDimension {
+    match (dim, arrayed) {
+        (ImageDimension::D1, _) => TextureViewDimension::D1,
+        (ImageDimension::D2, false) => TextureViewDimension::D2,
+        (ImageDimension::D2, true) => TextureViewDimension::D2Array,
+        (ImageDimension::D3, _) => TextureViewDimension::D3,
+        (ImageDimension::Cube, false) => TextureViewDimension::Cube,
+        (ImageDimension::Cube, true) => TextureViewDimension::CubeArray,
+    }
+}
+
+fn texture_format(format: StorageFormat) -> TextureFormat {
+    match format {
+        StorageFormat::R8Unorm => TextureFormat::R8Unorm,
+        StorageFormat::R8Snorm => TextureFormat::R8Snorm,
+        StorageFormat::R8Uint => TextureFormat::R8Uint,
+        StorageFormat::R8Sint => TextureFormat::R8Sint,
+        StorageFormat::R16Uint => TextureFormat::R16Uint,
+        StorageFormat::R16Sint => TextureFormat::R16Sint,
+        StorageFormat::R16Float => TextureFormat::R16Float,
+        StorageFormat::Rg8Unorm => TextureFormat::Rg8Unorm,
+        StorageFormat::Rg8Snorm => TextureFormat::Rg8Snorm,
+        StorageFormat::Rg8Uint => TextureFormat::Rg8Uint,
+        StorageFormat::Rg8Sint => TextureFormat::Rg8Sint,
+        StorageFormat::R32Uint => TextureFormat::R32Uint,
+        StorageFormat::R32Sint => TextureFormat::R32Sint,
+        StorageFormat::R32Float => TextureFormat::R32Float,
+        StorageFormat::Rg16Uint => TextureFormat::Rg16Uint,
+        StorageFormat::Rg16Sint => TextureFormat::Rg16Sint,
+        StorageFormat::Rg16Float => TextureFormat::Rg16Float,
+        StorageFormat::Rgba8Unorm => TextureFormat::Rgba8Unorm,
+        StorageFormat::Rgba8Snorm => TextureFormat::Rgba8Snorm,
+        StorageFormat::Rgba8Uint => TextureFormat::Rgba8Uint,
+        StorageFormat::Rgba8Sint => TextureFormat::Rgba8Sint,
+        StorageFormat::Bgra8Unorm => TextureFormat::Bgra8Unorm,
+        StorageFormat::Rgb10a2Uint => TextureFormat::Rgb10a2Uint,
+        StorageFormat::Rgb10a2Unorm => TextureFormat::Rgb10a2Unorm,
+        StorageFormat::Rg11b10Float => TextureFormat::Rg11b10Float,
+        StorageFormat::Rg32Uint => TextureFormat::Rg32Uint,
+        StorageFormat::Rg32Sint => TextureFormat::Rg32Sint,
+        StorageFormat::Rg32Float => TextureFormat::Rg32Float,
+        StorageFormat::Rgba16Uint => TextureFormat::Rgba16Uint,
+        StorageFormat::Rgba16Sint => TextureFormat::Rgba16Sint,
+        StorageFormat::Rgba16Float => TextureFormat::Rgba16Float,
+        StorageFormat::Rgba32Uint => TextureFormat::Rgba32Uint,
+        StorageFormat::Rgba32Sint => TextureFormat::Rgba32Sint,
+        StorageFormat::Rgba32Float => TextureFormat::Rgba32Float,
+        StorageFormat::R16Unorm => TextureFormat::R16Unorm,
+        StorageFormat::R16Snorm => TextureFormat::R16Snorm,
+        StorageFormat::Rg16Unorm => TextureFormat::Rg16Unorm,
+        StorageFormat::Rg16Snorm => TextureFormat::Rg16Snorm,
+        StorageFormat::Rgba16Unorm => TextureFormat::Rgba16Unorm,
+        StorageFormat::Rgba16Snorm => TextureFormat::Rgba16Snorm,
+    }
+}
+
+fn undecorated_name(name: &str) -> String {
+    match name.find(NAGA_OIL_DECORATION) {
+        Some(index) => name[..index].to_owned(),
+        None => name.to_owned(),
+    }
+}
@@ -0,0 +1,19 @@
+use bevy::prelude::Res;
+
+use crate::{traits::ComputeWorker, worker::AppComputeWorker};
+
+/// Run condition that is true on the frames the results of [`AppComputeWorker<W>`] can be read.
+///
+/// ```
+/// app.add_systems(Update, read_results.run_if(worker_ready::<MyWorker>()));
+/// ```
+pub fn worker_ready<W: ComputeWorker>(
+) -> impl FnMut(Option<Res<AppComputeWorker<W>>>) -> bool + Clone {
+    |worker: Option<Res<AppComputeWorker<W>>>| worker.is_some_and(|worker| worker.ready())
+}
+
+/// Run condition that is true when [`AppComputeWorker<W>`] has no run in progress.
+pub fn worker_idle<W: ComputeWorker>(
+) -> impl FnMut(Option<Res<AppComputeWorker<W>>>) -> bool + Clone {
+    |worker: Option<Res<AppComputeWorker<W>>>| worker.is_some_and(|worker| worker.idle())
+}
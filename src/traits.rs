@@ -33,6 +33,14 @@ pub trait ComputeShader: TypePath + Send + Sync + 'static {
     fn shader_defs<'a>() -> &'a [ShaderDefVal] {
         &[]
     }
+
+    /// If set, the workgroup size is added to the shader defs as `WORKGROUP_SIZE_X`,
+    /// `WORKGROUP_SIZE_Y` and `WORKGROUP_SIZE_Z`, to be used in the shader as
+    /// `@workgroup_size(#{WORKGROUP_SIZE_X}, #{WORKGROUP_SIZE_Y}, #{WORKGROUP_SIZE_Z})`.
+    fn workgroup_size() -> Option<[u32; 3]> {
+        None
+    }
+
     fn push_constant_ranges<'a>() -> &'a [PushConstantRange] {
         &[]
     }
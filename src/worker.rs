@@ -1,24 +1,51 @@
 use core::panic;
 use std::{
+    collections::{BTreeMap, VecDeque},
     marker::PhantomData,
-    ops::Deref,
+    ops::{Deref, Range},
+    sync::Arc,
     time::{Duration, SystemTime},
 };
 
 use bevy::{
-    prelude::{Res, ResMut, Resource},
+    asset::{handle_internal_asset_events, LoadState, RecursiveDependencyLoadState},
+    ecs::system::SystemState,
+    log::{error, warn},
+    prelude::{
+        AssetEvent, AssetServer, Assets, Event, EventReader, EventWriter, Res, ResMut, Resource,
+        Shader, World,
+    },
     render::{
-        render_resource::{Buffer, ComputePipeline},
+        render_resource::{
+            encase::{
+                internal::{CreateFrom, ReadFrom, WriteInto},
+                StorageBuffer, UniformBuffer,
+            },
+            Buffer, ComputePipeline, Sampler, ShaderSize, ShaderType, Texture, TextureView,
+        },
         renderer::{RenderDevice, RenderQueue},
     },
-    utils::HashMap,
+    time::{Time, Timer, TimerMode},
+    utils::{HashMap, HashSet, Instant},
+};
+use bytemuck::{bytes_of, cast_slice, pod_read_unaligned, AnyBitPattern, NoUninit};
+use naga::{Module, StorageAccess};
+use wgpu::{
+    util::BufferInitDescriptor, BindGroupEntry, BindingResource, BufferDescriptor, BufferUsages,
+    BufferView, CommandEncoder, CommandEncoderDescriptor, ComputePassDescriptor,
+    ComputePassTimestampWrites, Features, ImageCopyBuffer, ImageDataLayout, QuerySet,
+    QuerySetDescriptor, QueryType, StorageTextureAccess, COPY_BUFFER_ALIGNMENT,
+    COPY_BYTES_PER_ROW_ALIGNMENT, MAP_ALIGNMENT, QUERY_SIZE,
 };
-use bytemuck::{bytes_of, cast_slice, from_bytes, AnyBitPattern, NoUninit};
-use wgpu::{BindGroupEntry, CommandEncoder, CommandEncoderDescriptor, ComputePassDescriptor};
 
 use crate::{
     error::{Error, Result},
+    extract_shaders,
+    handle::{ArrayKind, BufferHandle, Staging, Uniform},
+    job::{ComputeJob, PendingJob},
+    layout::RustLayout,
     pipeline_cache::{AppPipelineCache, CachedAppComputePipelineId},
+    reflection::{GlobalKind, ReflectedGlobal, ShaderReflection},
     traits::ComputeWorker,
     worker_builder::AppComputeWorkerBuilder,
 };
@@ -27,8 +54,42 @@ use crate::{
 pub enum RunMode {
     Continuous,
     OneShot(bool),
+    /// Run once every this many frames.
+    EveryNFrames(u32),
+    /// Run once per period of the bevy `Time`.
+    Every(Duration),
+    /// Run only when a buffer was written to since the last run.
+    OnInputChange,
+}
+
+/// Sent when a run of [`AppComputeWorker<W>`] is done, and its results can be read.
+#[derive(Event)]
+pub struct ComputeFinished<W: ComputeWorker> {
+    /// How many runs the worker completed, this one included.
+    pub run: u64,
+    /// Time the GPU spent executing the compute passes of the run, measured with timestamp queries.
+    ///
+    /// `None` if the render device doesn't support [`Features::TIMESTAMP_QUERY`].
+    pub gpu_time: Option<Duration>,
+    /// Wall-clock time between the submission of the run and the frame it was found complete on.
+    ///
+    /// This is measured on the CPU and includes the time spent waiting for the next poll, so it is
+    /// an upper bound of the time the GPU spent executing the run.
+    pub wall_time: Duration,
+    _phantom: PhantomData<W>,
 }
 
+/// How long [`AppComputeWorker::run_blocking`] and precomputed workers wait for the shaders of the
+/// worker to load and its pipelines to compile by default.
+pub(crate) const PIPELINE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Reads the shader events for [`AppComputeWorker::run_blocking`], between frames.
+type ShaderEvents = SystemState<(
+    ResMut<'static, AppPipelineCache>,
+    Res<'static, Assets<Shader>>,
+    EventReader<'static, 'static, AssetEvent<Shader>>,
+)>;
+
 #[derive(PartialEq)]
 pub enum WorkerState {
     Created,
@@ -41,19 +102,374 @@ pub enum WorkerState {
 pub(crate) enum Step {
     ComputePass(ComputePass),
     Swap(String, String),
+    /// Copy `(src_offset, dst_offset, size)` byte ranges from `src` to `dst`, all of `src` if empty.
+    Copy {
+        src: String,
+        dst: String,
+        ranges: Vec<(u64, u64, u64)>,
+    },
+    /// Set every byte of the buffer to zero.
+    Clear(String),
+    /// Repeat `value` over the whole buffer, by copying from `source`.
+    Fill {
+        buffer: String,
+        value: Vec<u8>,
+        /// Created on the first run, and again whenever the buffer was resized.
+        source: Option<Buffer>,
+    },
+    Custom(CustomStep),
+    /// Run the `len` following steps `count` times.
+    Repeat {
+        count: u32,
+        len: usize,
+    },
+    /// Run the `len` following steps until `condition` holds for the start of `flag`, checked by
+    /// copying it to `staging` and reading it back after each iteration.
+    Converge {
+        flag: String,
+        staging: Buffer,
+        condition: ConvergeCondition,
+        max_iterations: u32,
+        len: usize,
+    },
+}
+
+impl Step {
+    /// The number of steps following this one that belong to it.
+    pub(crate) fn group_len(&self) -> usize {
+        match self {
+            Step::Repeat { len, .. } | Step::Converge { len, .. } => *len,
+            _ => 0,
+        }
+    }
+}
+
+/// The condition of a step added with `add_converge`, given the bytes read back from its flag.
+#[derive(Clone)]
+pub(crate) struct ConvergeCondition(pub(crate) Arc<ConvergeFn>);
+
+type ConvergeFn = dyn Fn(&[u8]) -> bool + Send + Sync;
+
+impl std::fmt::Debug for ConvergeCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ConvergeCondition")
+    }
+}
+
+/// A `Step::Converge` waiting for its flag to be read back.
+#[derive(Clone, Copy, Debug)]
+struct PendingConvergence {
+    step: usize,
+    iterations: u32,
+}
+
+/// A step of a worker, by its index in the builder or by the name given with `label()`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StepId<'a> {
+    Index(usize),
+    Label(&'a str),
+}
+
+impl From<usize> for StepId<'_> {
+    fn from(index: usize) -> Self {
+        StepId::Index(index)
+    }
+}
+
+impl<'a> From<&'a str> for StepId<'a> {
+    fn from(label: &'a str) -> Self {
+        StepId::Label(label)
+    }
+}
+
+impl std::fmt::Display for StepId<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StepId::Index(index) => write!(f, "{index}"),
+            StepId::Label(label) => write!(f, "{label}"),
+        }
+    }
+}
+
+/// A step added with `add_custom_step`.
+#[derive(Clone)]
+pub(crate) struct CustomStep(pub(crate) Arc<dyn Fn(&mut StepContext) + Send + Sync>);
+
+impl std::fmt::Debug for CustomStep {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("CustomStep")
+    }
+}
+
+/// What a custom step can use to record its own commands, in order with the other steps of the worker.
+pub struct StepContext<'a> {
+    pub encoder: &'a mut CommandEncoder,
+    pub render_device: &'a RenderDevice,
+    /// The buffers of the worker, by name.
+    pub buffers: &'a HashMap<String, Buffer>,
 }
 
 #[derive(Clone, Debug)]
 pub(crate) struct ComputePass {
-    pub(crate) workgroups: [u32; 3],
-    pub(crate) vars: Vec<String>,
+    pub(crate) dispatch: Dispatch,
+    pub(crate) vars: Vec<PassVar>,
+    /// If set, `vars` are filled from the shader reflection once its pipeline is ready.
+    pub(crate) reflect_vars: bool,
     pub(crate) shader_type_path: String,
+    /// Bytes set with `set_push_constants` at offset 0 before dispatching, if not empty.
+    pub(crate) push_constants: Vec<u8>,
+    /// End of the push constant ranges the shader declares for the compute stage.
+    pub(crate) push_constants_size: u32,
+    /// Number of bind group layouts the shader declares with `layouts()`, 0 if they are derived.
+    pub(crate) layout_count: u32,
+    /// [`ComputeShader::workgroup_size`](crate::traits::ComputeShader::workgroup_size), used by
+    /// [`Dispatch::Elements`] when the shader can't be reflected.
+    pub(crate) workgroup_size: Option<[u32; 3]>,
+}
+
+/// How many workgroups a compute pass dispatches.
+#[derive(Clone, Debug)]
+pub(crate) enum Dispatch {
+    Workgroups([u32; 3]),
+    /// Enough workgroups to cover this many elements, given the `@workgroup_size` of the shader.
+    Elements([u32; 3]),
+    /// Read from three `u32` at `offset` in `buffer`, written by a previous pass.
+    Indirect {
+        buffer: String,
+        offset: u64,
+    },
+}
+
+/// A variable bound to a compute pass, at `@group(group) @binding(binding)`.
+#[derive(Clone, Debug)]
+pub(crate) struct PassVar {
+    pub(crate) group: u32,
+    pub(crate) binding: u32,
+    pub(crate) name: String,
+}
+
+/// How a buffer was declared in the builder, to check it against the shaders using it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum BufferKind {
+    Uniform,
+    Storage,
+    RwStorage,
+}
+
+impl std::fmt::Display for BufferKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BufferKind::Uniform => write!(f, "uniform buffer"),
+            BufferKind::Storage => write!(f, "read only storage buffer"),
+            BufferKind::RwStorage => write!(f, "read/write storage buffer"),
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
 pub(crate) struct StagingBuffer {
     pub(crate) mapped: bool,
     pub(crate) buffer: Buffer,
+    /// Bytes copied from the buffer on each run, the whole buffer if `None`.
+    pub(crate) range: Option<Range<u64>>,
+    /// Bytes copied by the last run, and thus the ones that can be read.
+    pub(crate) copied: Range<u64>,
+}
+
+impl StagingBuffer {
+    /// Create a staging buffer of `size` bytes, mapped so it can be read before the first run.
+    pub(crate) fn new(render_device: &RenderDevice, name: &str, size: u64) -> Self {
+        Self {
+            mapped: true,
+            buffer: render_device.create_buffer(&BufferDescriptor {
+                label: Some(name),
+                size,
+                usage: BufferUsages::MAP_READ | BufferUsages::COPY_DST,
+                mapped_at_creation: true,
+            }),
+            range: None,
+            copied: 0..size,
+        }
+    }
+
+    /// `range` widened to the alignments wgpu requires for copies and mappings.
+    fn aligned(&self, range: &Range<u64>) -> Range<u64> {
+        let start = range.start - range.start % MAP_ALIGNMENT;
+        let end = range
+            .end
+            .next_multiple_of(COPY_BUFFER_ALIGNMENT)
+            .min(self.buffer.size());
+        start..end
+    }
+}
+
+/// Timestamp queries measuring how long the GPU spends on the compute passes of a run.
+///
+/// Each batch writes a timestamp at the beginning of its first pass and at the end of its last
+/// one, which are resolved and read back with the staging buffers.
+struct GpuTimer {
+    query_set: QuerySet,
+    resolve_buffer: Buffer,
+    readback_buffer: Buffer,
+    /// Nanoseconds per timestamp tick.
+    period: f32,
+    /// Set once a pass of the batch being encoded wrote its timestamps.
+    written: bool,
+    /// Set while the timestamps of the submitted batch are being mapped.
+    mapping: bool,
+}
+
+impl GpuTimer {
+    /// Create a timer if the render device supports [`Features::TIMESTAMP_QUERY`].
+    fn new(render_device: &RenderDevice, render_queue: &RenderQueue) -> Option<Self> {
+        if !render_device.features().contains(Features::TIMESTAMP_QUERY) {
+            return None;
+        }
+
+        let size = 2 * QUERY_SIZE as u64;
+        Some(Self {
+            query_set: render_device
+                .wgpu_device()
+                .create_query_set(&QuerySetDescriptor {
+                    label: Some("gpu_timer"),
+                    ty: QueryType::Timestamp,
+                    count: 2,
+                }),
+            resolve_buffer: render_device.create_buffer(&BufferDescriptor {
+                label: Some("gpu_timer_resolve"),
+                size,
+                usage: BufferUsages::QUERY_RESOLVE | BufferUsages::COPY_SRC,
+                mapped_at_creation: false,
+            }),
+            readback_buffer: render_device.create_buffer(&BufferDescriptor {
+                label: Some("gpu_timer_readback"),
+                size,
+                usage: BufferUsages::MAP_READ | BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            }),
+            period: render_queue.get_timestamp_period(),
+            written: false,
+            mapping: false,
+        })
+    }
+
+    /// Timestamps to write for the next pass of the batch being encoded.
+    fn timestamp_writes(&mut self) -> ComputePassTimestampWrites<'_> {
+        let first = !self.written;
+        self.written = true;
+
+        ComputePassTimestampWrites {
+            query_set: &self.query_set,
+            beginning_of_pass_write_index: first.then_some(0),
+            end_of_pass_write_index: Some(1),
+        }
+    }
+
+    /// Copy the timestamps of the batch being encoded to the readback buffer.
+    fn resolve(&self, encoder: &mut CommandEncoder) {
+        if !self.written {
+            return;
+        }
+
+        encoder.resolve_query_set(&self.query_set, 0..2, &self.resolve_buffer, 0);
+        encoder.copy_buffer_to_buffer(
+            &self.resolve_buffer,
+            0,
+            &self.readback_buffer,
+            0,
+            self.readback_buffer.size(),
+        );
+    }
+
+    /// Map the timestamps of the batch that was just submitted.
+    fn map(&mut self) {
+        if !self.written {
+            return;
+        }
+
+        self.readback_buffer
+            .slice(..)
+            .map_async(wgpu::MapMode::Read, |result| {
+                if let Some(err) = result.err() {
+                    panic!("{}", err.to_string());
+                }
+            });
+        self.written = false;
+        self.mapping = true;
+    }
+
+    /// Time the GPU spent on the batch that just completed, if it had any pass.
+    fn read(&mut self) -> Option<Duration> {
+        if !self.mapping {
+            return None;
+        }
+        self.mapping = false;
+
+        let ticks = {
+            let bytes = self.readback_buffer.slice(..).get_mapped_range();
+            let [start, end]: [u64; 2] = pod_read_unaligned(&bytes);
+            end.saturating_sub(start)
+        };
+        self.readback_buffer.unmap();
+
+        Some(Duration::from_nanos(
+            (ticks as f64 * self.period as f64) as u64,
+        ))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct ComputeTexture {
+    pub(crate) texture: Texture,
+    pub(crate) view: TextureView,
+    /// `None` for sampled textures.
+    pub(crate) access: Option<StorageTextureAccess>,
+}
+
+impl ComputeTexture {
+    /// Number of bytes of a single row of texels, padded to [`COPY_BYTES_PER_ROW_ALIGNMENT`]
+    /// as required by texture to buffer copies.
+    ///
+    /// `None` for formats that can't be copied as a whole, like depth and stencil formats.
+    pub(crate) fn padded_bytes_per_row(&self) -> Option<u32> {
+        let unpadded = self.unpadded_bytes_per_row()?;
+        Some(unpadded.div_ceil(COPY_BYTES_PER_ROW_ALIGNMENT) * COPY_BYTES_PER_ROW_ALIGNMENT)
+    }
+
+    pub(crate) fn unpadded_bytes_per_row(&self) -> Option<u32> {
+        let block_size = self.texture.format().block_copy_size(None)?;
+        Some(self.texture.width() * block_size)
+    }
+
+    /// Size of a buffer able to hold a full copy of this texture.
+    pub(crate) fn staging_size(&self) -> Option<u64> {
+        let size = self.texture.size();
+        let padded_bytes_per_row = self.padded_bytes_per_row()? as u64;
+        Some(padded_bytes_per_row * size.height as u64 * size.depth_or_array_layers as u64)
+    }
+
+    /// Error for the formats [`Self::padded_bytes_per_row`] doesn't support.
+    pub(crate) fn uncopyable(&self, name: &str) -> Error {
+        Error::UnsupportedTextureFormat {
+            texture: name.to_owned(),
+            format: self.texture.format(),
+            usage: String::from("in a copy to a staging buffer"),
+        }
+    }
+}
+
+/// Bytes of a staging buffer, within the mapped range.
+struct MappedBytes<'a> {
+    view: BufferView<'a>,
+    range: Range<usize>,
+}
+
+impl Deref for MappedBytes<'_> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.view[self.range.clone()]
+    }
 }
 
 /// Struct to manage data transfers from/to the GPU
@@ -69,16 +485,52 @@ pub struct AppComputeWorker<W: ComputeWorker> {
     render_queue: RenderQueue,
     cached_pipeline_ids: HashMap<String, CachedAppComputePipelineId>,
     pipelines: HashMap<String, Option<ComputePipeline>>,
+    reflections: HashMap<String, Arc<ShaderReflection>>,
     buffers: HashMap<String, Buffer>,
+    buffer_kinds: HashMap<String, BufferKind>,
+    buffer_layouts: HashMap<String, RustLayout>,
+    textures: HashMap<String, ComputeTexture>,
+    samplers: HashMap<String, Sampler>,
     staging_buffers: HashMap<String, StagingBuffer>,
     steps: Vec<Step>,
+    step_labels: HashMap<String, usize>,
+    /// Index of the steps skipped with `set_step_enabled`.
+    disabled_steps: HashSet<usize>,
+    pending_convergence: Option<PendingConvergence>,
+    /// Iterations taken by each `Step::Converge` on the last run, by index.
+    iterations: HashMap<usize, u32>,
     command_encoder: Option<CommandEncoder>,
     run_mode: RunMode,
+    /// Set by `execute()` and by the run modes running at a lower rate, until the next run.
+    run_requested: bool,
+    /// Set when a buffer is written to, until the next run.
+    inputs_changed: bool,
+    /// Frames since the last run, for `RunMode::EveryNFrames`.
+    frames: u32,
+    /// Period of `RunMode::Every`.
+    timer: Timer,
     submission_queue_processed: bool,
+    /// Jobs waiting for the worker to be idle, in the order they were submitted.
+    jobs: VecDeque<PendingJob<W>>,
+    /// The job whose inputs were written, completed at the end of the next run.
+    current_job: Option<PendingJob<W>>,
+    /// Completed runs, for `ComputeFinished`.
+    runs: u64,
+    run_started: SystemTime,
+    gpu_timer: Option<GpuTimer>,
+    shader_events: Option<ShaderEvents>,
+    /// GPU time of the batches of the current run completed so far.
+    gpu_time: Duration,
     /// Maximum duration the compute shader will run asyncronously before being set to synchronous.
     ///
     /// 0 seconds means the shader will immediately be polled synchronously. None emeans the shader will only run asynchronously.
     maximum_async_time: Option<Duration>,
+    grow_on_write: bool,
+    /// First error found checking the passes once their pipelines were ready.
+    /// The worker doesn't run anymore once set.
+    invalid: Option<Error>,
+    /// Set for workers created with `build()`, which panic on such errors instead.
+    pub(crate) panic_on_invalid: bool,
     _phantom: PhantomData<W>,
 }
 
@@ -96,6 +548,7 @@ impl<W: ComputeWorker> From<&AppComputeWorkerBuilder<'_, W>> for AppComputeWorke
 
         let command_encoder =
             Some(render_device.create_command_encoder(&CommandEncoderDescriptor { label: None }));
+        let gpu_timer = GpuTimer::new(&render_device, &render_queue);
 
         Self {
             state: WorkerState::Created,
@@ -103,14 +556,41 @@ impl<W: ComputeWorker> From<&AppComputeWorkerBuilder<'_, W>> for AppComputeWorke
             render_queue,
             cached_pipeline_ids: builder.cached_pipeline_ids.clone(),
             pipelines,
+            reflections: HashMap::default(),
             buffers: builder.buffers.clone(),
+            buffer_kinds: builder.buffer_kinds.clone(),
+            buffer_layouts: builder.buffer_layouts.clone(),
+            textures: builder.textures.clone(),
+            samplers: builder.samplers.clone(),
             staging_buffers: builder.staging_buffers.clone(),
             steps: builder.steps.clone(),
+            step_labels: builder.step_labels.clone(),
+            disabled_steps: HashSet::default(),
+            pending_convergence: None,
+            iterations: HashMap::default(),
             command_encoder,
             run_mode: builder.run_mode,
+            run_requested: false,
+            // Run once with the initial contents of the buffers
+            inputs_changed: true,
+            frames: 0,
+            timer: match builder.run_mode {
+                RunMode::Every(period) => Timer::new(period, TimerMode::Repeating),
+                _ => Timer::default(),
+            },
             _phantom: PhantomData,
             maximum_async_time: builder.maximum_async_time,
+            grow_on_write: builder.grow_on_write,
+            invalid: None,
+            panic_on_invalid: false,
             submission_queue_processed: false,
+            jobs: VecDeque::new(),
+            current_job: None,
+            runs: 0,
+            run_started: SystemTime::now(),
+            gpu_timer,
+            shader_events: None,
+            gpu_time: Duration::ZERO,
         }
     }
 }
@@ -120,21 +600,17 @@ impl<W: ComputeWorker> AppComputeWorker<W> {
     fn dispatch(&mut self, index: usize) -> Result<()> {
         let compute_pass = match &self.steps[index] {
             Step::ComputePass(compute_pass) => compute_pass,
-            Step::Swap(_, _) => return Err(Error::InvalidStep(format!("{:?}", self.steps[index]))),
+            _ => return Err(Error::InvalidStep(format!("{:?}", self.steps[index]))),
         };
 
-        let mut entries = vec![];
-        for (index, var) in compute_pass.vars.iter().enumerate() {
-            let Some(buffer) = self.buffers.get(var) else {
-                return Err(Error::BufferNotFound(var.to_owned()));
-            };
-
+        let mut entries: BTreeMap<u32, Vec<BindGroupEntry>> = BTreeMap::new();
+        for var in &compute_pass.vars {
             let entry = BindGroupEntry {
-                binding: index as u32,
-                resource: buffer.as_entire_binding(),
+                binding: var.binding,
+                resource: self.binding_resource(&var.name)?,
             };
 
-            entries.push(entry);
+            entries.entry(var.group).or_default().push(entry);
         }
 
         let Some(maybe_pipeline) = self.pipelines.get(&compute_pass.shader_type_path) else {
@@ -145,10 +621,47 @@ impl<W: ComputeWorker> AppComputeWorker<W> {
             return Err(Error::PipelineNotReady);
         };
 
-        let bind_group_layout = pipeline.get_bind_group_layout(0);
-        let bind_group =
-            self.render_device
-                .create_bind_group(None, &bind_group_layout.into(), &entries);
+        if let Some(group_count) = self.bind_group_count(compute_pass) {
+            check_bind_groups(compute_pass, group_count)?;
+        }
+
+        // Every group up to the highest one used must be set, even if the shader leaves gaps.
+        let group_count = entries.keys().next_back().map_or(0, |group| group + 1);
+        let bind_groups = (0..group_count)
+            .map(|group| {
+                let bind_group_layout = pipeline.get_bind_group_layout(group);
+                self.render_device.create_bind_group(
+                    None,
+                    &bind_group_layout.into(),
+                    entries.get(&group).map_or(&[], Vec::as_slice),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let workgroups = match &compute_pass.dispatch {
+            Dispatch::Workgroups(workgroups) => *workgroups,
+            Dispatch::Elements(elements) => {
+                let Some(workgroup_size) = self
+                    .reflections
+                    .get(&compute_pass.shader_type_path)
+                    .and_then(|reflection| reflection.workgroup_size)
+                    .or(compute_pass.workgroup_size)
+                else {
+                    return Err(Error::ReflectionUnavailable(
+                        compute_pass.shader_type_path.clone(),
+                    ));
+                };
+
+                std::array::from_fn(|i| elements[i].div_ceil(workgroup_size[i]))
+            }
+            Dispatch::Indirect { buffer, .. } => {
+                if !self.buffers.contains_key(buffer) {
+                    return Err(Error::BufferNotFound(buffer.clone()));
+                }
+
+                [0; 3]
+            }
+        };
 
         let Some(encoder) = &mut self.command_encoder else {
             return Err(Error::EncoderIsNone);
@@ -156,29 +669,61 @@ impl<W: ComputeWorker> AppComputeWorker<W> {
         {
             let mut cpass = encoder.begin_compute_pass(&ComputePassDescriptor {
                 label: None,
-                timestamp_writes: None,
+                timestamp_writes: self.gpu_timer.as_mut().map(GpuTimer::timestamp_writes),
             });
             cpass.set_pipeline(pipeline);
-            cpass.set_bind_group(0, &bind_group, &[]);
-            cpass.dispatch_workgroups(
-                compute_pass.workgroups[0],
-                compute_pass.workgroups[1],
-                compute_pass.workgroups[2],
-            )
+            for (group, bind_group) in bind_groups.iter().enumerate() {
+                cpass.set_bind_group(group as u32, bind_group, &[]);
+            }
+            if !compute_pass.push_constants.is_empty() {
+                cpass.set_push_constants(0, &compute_pass.push_constants);
+            }
+            if let Dispatch::Indirect { buffer, offset } = &compute_pass.dispatch {
+                cpass.dispatch_workgroups_indirect(&self.buffers[buffer], *offset);
+            } else {
+                let [x, y, z] = workgroups;
+                cpass.dispatch_workgroups(x, y, z);
+            }
         }
 
         Ok(())
     }
 
+    /// Find the buffer, texture or sampler called `name` and return it as a binding resource.
+    #[inline]
+    fn binding_resource(&self, name: &str) -> Result<BindingResource<'_>> {
+        if let Some(buffer) = self.buffers.get(name) {
+            return Ok(buffer.as_entire_binding());
+        }
+
+        if let Some(texture) = self.textures.get(name) {
+            return Ok(BindingResource::TextureView(&texture.view));
+        }
+
+        if let Some(sampler) = self.samplers.get(name) {
+            return Ok(BindingResource::Sampler(sampler));
+        }
+
+        Err(Error::BufferNotFound(name.to_owned()))
+    }
+
     #[inline]
     fn swap(&mut self, index: usize) -> Result<()> {
         let (buf_a_name, buf_b_name) = match &self.steps[index] {
-            Step::ComputePass(_) => {
-                return Err(Error::InvalidStep(format!("{:?}", self.steps[index])))
-            }
             Step::Swap(a, b) => (a.as_str(), b.as_str()),
+            _ => return Err(Error::InvalidStep(format!("{:?}", self.steps[index]))),
         };
 
+        if self.textures.contains_key(buf_a_name) && self.textures.contains_key(buf_b_name) {
+            let [texture_a, texture_b] = self
+                .textures
+                .get_many_mut([buf_a_name, buf_b_name])
+                .unwrap();
+            std::mem::swap(texture_a, texture_b);
+
+            return Ok(());
+        }
+
         if !self.buffers.contains_key(buf_a_name) {
             return Err(Error::BufferNotFound(buf_a_name.to_owned()));
         }
@@ -194,132 +739,984 @@ impl<W: ComputeWorker> AppComputeWorker<W> {
     }
 
     #[inline]
-    fn read_staging_buffers(&mut self) -> Result<&mut Self> {
-        for (name, staging_buffer) in &self.staging_buffers {
-            let Some(encoder) = &mut self.command_encoder else {
-                return Err(Error::EncoderIsNone);
-            };
-            let Some(buffer) = self.buffers.get(name) else {
-                return Err(Error::BufferNotFound(name.to_owned()));
-            };
+    fn copy(&mut self, index: usize) -> Result<()> {
+        let Step::Copy { src, dst, ranges } = &self.steps[index] else {
+            return Err(Error::InvalidStep(format!("{:?}", self.steps[index])));
+        };
 
-            encoder.copy_buffer_to_buffer(
-                buffer,
-                0,
-                &staging_buffer.buffer,
-                0,
-                staging_buffer.buffer.size(),
-            );
-        }
-        Ok(self)
-    }
+        let Some(src_buffer) = self.buffers.get(src) else {
+            return Err(Error::BufferNotFound(src.clone()));
+        };
 
-    #[inline]
-    fn map_staging_buffers(&mut self) -> &mut Self {
-        for (_, staging_buffer) in self.staging_buffers.iter_mut() {
-            let read_buffer_slice = staging_buffer.buffer.slice(..);
+        let Some(dst_buffer) = self.buffers.get(dst) else {
+            return Err(Error::BufferNotFound(dst.clone()));
+        };
 
-            read_buffer_slice.map_async(wgpu::MapMode::Read, |result| {
-                if let Some(err) = result.err() {
-                    panic!("{}", err.to_string());
-                }
-            });
+        // Buffers may have been resized since the worker was built
+        let ranges = copy_ranges(src, src_buffer, dst, dst_buffer, ranges)?;
+
+        let Some(encoder) = &mut self.command_encoder else {
+            return Err(Error::EncoderIsNone);
+        };
+        for (src_offset, dst_offset, size) in ranges {
+            encoder.copy_buffer_to_buffer(src_buffer, src_offset, dst_buffer, dst_offset, size);
         }
-        self
+
+        Ok(())
     }
 
-    /// Read data from `target` staging buffer, return raw bytes
     #[inline]
-    pub fn try_read_raw<'a>(&'a self, target: &str) -> Result<(impl Deref<Target = [u8]> + 'a)> {
-        let Some(staging_buffer) = &self.staging_buffers.get(target) else {
-            return Err(Error::StagingBufferNotFound(target.to_owned()));
+    fn clear(&mut self, index: usize) -> Result<()> {
+        let Step::Clear(name) = &self.steps[index] else {
+            return Err(Error::InvalidStep(format!("{:?}", self.steps[index])));
         };
 
-        let result = staging_buffer.buffer.slice(..).get_mapped_range();
+        let Some(buffer) = self.buffers.get(name) else {
+            return Err(Error::BufferNotFound(name.clone()));
+        };
 
-        Ok(result)
-    }
+        let Some(encoder) = &mut self.command_encoder else {
+            return Err(Error::EncoderIsNone);
+        };
+        encoder.clear_buffer(buffer, 0, None);
 
-    /// Read data from `target` staging buffer, return raw bytes
-    /// Panics on error.
-    #[inline]
-    pub fn read_raw<'a>(&'a self, target: &str) -> (impl Deref<Target = [u8]> + 'a) {
-        self.try_read_raw(target).unwrap()
+        Ok(())
     }
 
-    /// Try Read data from `target` staging buffer, return a single `B: Pod`
     #[inline]
-    pub fn try_read<B: AnyBitPattern>(&self, target: &str) -> Result<B> {
-        let result = *from_bytes::<B>(&self.try_read_raw(target)?);
-        Ok(result)
-    }
+    fn fill(&mut self, index: usize) -> Result<()> {
+        let Step::Fill {
+            buffer: name,
+            value,
+            source,
+        } = &mut self.steps[index]
+        else {
+            return Err(Error::InvalidStep(format!("{:?}", self.steps[index])));
+        };
 
-    /// Try Read data from `target` staging buffer, return a single `B: Pod`
-    /// In case of error, this function will panic.
-    #[inline]
-    pub fn read<B: AnyBitPattern>(&self, target: &str) -> B {
-        self.try_read(target).unwrap()
-    }
+        let Some(buffer) = self.buffers.get(name) else {
+            return Err(Error::BufferNotFound(name.clone()));
+        };
 
-    /// Try Read data from `target` staging buffer, return a vector of `B: Pod`
-    #[inline]
-    pub fn try_read_vec<B: AnyBitPattern>(&self, target: &str) -> Result<Vec<B>> {
-        let bytes = self.try_read_raw(target)?;
-        Ok(cast_slice::<u8, B>(&bytes).to_vec())
-    }
+        if source
+            .as_ref()
+            .is_none_or(|source| source.size() != buffer.size())
+        {
+            let contents: Vec<u8> = value
+                .iter()
+                .copied()
+                .cycle()
+                .take(buffer.size() as usize)
+                .collect();
+            *source = Some(
+                self.render_device
+                    .create_buffer_with_data(&BufferInitDescriptor {
+                        label: Some(name),
+                        contents: &contents,
+                        usage: BufferUsages::COPY_SRC,
+                    }),
+            );
+        }
 
-    /// Try Read data from `target` staging buffer, return a vector of `B: Pod`
-    /// In case of error, this function will panic.
-    #[inline]
-    pub fn read_vec<B: AnyBitPattern>(&self, target: &str) -> Vec<B> {
-        self.try_read_vec(target).unwrap()
+        let Some(encoder) = &mut self.command_encoder else {
+            return Err(Error::EncoderIsNone);
+        };
+        if let Some(source) = source {
+            encoder.copy_buffer_to_buffer(source, 0, buffer, 0, buffer.size());
+        }
+
+        Ok(())
     }
 
-    /// Write data to `target` buffer.
     #[inline]
-    pub fn try_write<T: NoUninit>(&mut self, target: &str, data: &T) -> Result<()> {
-        let Some(buffer) = &self.buffers.get(target) else {
-            return Err(Error::BufferNotFound(target.to_owned()));
+    fn custom(&mut self, index: usize) -> Result<()> {
+        let Step::Custom(CustomStep(step)) = &self.steps[index] else {
+            return Err(Error::InvalidStep(format!("{:?}", self.steps[index])));
         };
 
-        let bytes = bytes_of(data);
-
-        self.render_queue.write_buffer(buffer, 0, bytes);
+        let Some(encoder) = &mut self.command_encoder else {
+            return Err(Error::EncoderIsNone);
+        };
+        step(&mut StepContext {
+            encoder,
+            render_device: &self.render_device,
+            buffers: &self.buffers,
+        });
 
         Ok(())
     }
 
-    /// Write data to `target` buffer.
-    /// In case of error, this function will panic.
-    #[inline]
-    pub fn write<T: NoUninit>(&mut self, target: &str, data: &T) {
-        self.try_write(target, data).unwrap()
+    /// Encode the enabled steps in `steps`, repeating the ones inside `Step::Repeat`.
+    ///
+    /// Stops after the first iteration of a `Step::Converge` and returns true, the following steps
+    /// are encoded by `next_batch` once it has converged.
+    fn run_steps(&mut self, steps: Range<usize>) -> Result<bool> {
+        let mut i = steps.start;
+        while i < steps.end {
+            let next = i + 1 + self.steps[i].group_len();
+
+            if !self.disabled_steps.contains(&i) {
+                match self.steps[i] {
+                    Step::ComputePass(_) => self.dispatch(i)?,
+                    Step::Swap(_, _) => self.swap(i)?,
+                    Step::Copy { .. } => self.copy(i)?,
+                    Step::Clear(_) => self.clear(i)?,
+                    Step::Fill { .. } => self.fill(i)?,
+                    Step::Custom(_) => self.custom(i)?,
+                    Step::Repeat { count, .. } => {
+                        for _ in 0..count {
+                            self.run_steps(i + 1..next)?;
+                        }
+                    }
+                    Step::Converge { .. } => {
+                        self.run_steps(i + 1..next)?;
+                        self.copy_flag(i)?;
+                        self.pending_convergence = Some(PendingConvergence {
+                            step: i,
+                            iterations: 1,
+                        });
+                        return Ok(true);
+                    }
+                }
+            }
+
+            i = next;
+        }
+
+        Ok(false)
     }
 
-    /// Write data to `target` buffer.
     #[inline]
-    pub fn try_write_slice<T: NoUninit>(&mut self, target: &str, data: &[T]) -> Result<()> {
-        let Some(buffer) = &self.buffers.get(target) else {
-            return Err(Error::BufferNotFound(target.to_owned()));
+    fn copy_flag(&mut self, index: usize) -> Result<()> {
+        let Step::Converge { flag, staging, .. } = &self.steps[index] else {
+            return Err(Error::InvalidStep(format!("{:?}", self.steps[index])));
         };
 
-        let bytes = cast_slice(data);
+        let Some(buffer) = self.buffers.get(flag) else {
+            return Err(Error::BufferNotFound(flag.clone()));
+        };
 
-        self.render_queue.write_buffer(buffer, 0, bytes);
+        let Some(encoder) = &mut self.command_encoder else {
+            return Err(Error::EncoderIsNone);
+        };
+        encoder.copy_buffer_to_buffer(buffer, 0, staging, 0, staging.size());
 
         Ok(())
     }
 
-    /// Write data to `target` buffer.
-    /// In case of error, this function will panic.
-    #[inline]
-    pub fn write_slice<T: NoUninit>(&mut self, target: &str, data: &[T]) {
-        self.try_write_slice(target, data).unwrap()
-    }
+    /// Submit the encoded steps, reading back either the flag of a pending convergence or
+    /// the staging buffers.
+    fn submit_batch(&mut self, converging: bool) -> Result<()> {
+        if !converging {
+            self.read_staging_buffers()?;
+        }
 
-    fn submit(&mut self) -> &mut Self {
-        let encoder = self.command_encoder.take().unwrap();
-        self.render_queue.submit(Some(encoder.finish()));
+        if let (Some(gpu_timer), Some(encoder)) = (&self.gpu_timer, &mut self.command_encoder) {
+            gpu_timer.resolve(encoder);
+        }
+
+        self.submit();
+
+        if let Some(gpu_timer) = &mut self.gpu_timer {
+            gpu_timer.map();
+        }
+
+        match self.pending_convergence {
+            Some(PendingConvergence { step, .. }) if converging => {
+                let Step::Converge { staging, .. } = &self.steps[step] else {
+                    return Err(Error::InvalidStep(format!("{:?}", self.steps[step])));
+                };
+
+                staging.slice(..).map_async(wgpu::MapMode::Read, |result| {
+                    if let Some(err) = result.err() {
+                        panic!("{}", err.to_string());
+                    }
+                });
+            }
+            _ => {
+                self.map_staging_buffers();
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Once a batch is done, check the flag of the pending convergence, if any, and submit either
+    /// another iteration or the following steps.
+    ///
+    /// Returns false if there was nothing left to submit.
+    fn next_batch(&mut self) -> Result<bool> {
+        if let Some(gpu_time) = self.gpu_timer.as_mut().and_then(GpuTimer::read) {
+            self.gpu_time += gpu_time;
+        }
+
+        let Some(mut pending) = self.pending_convergence.take() else {
+            return Ok(false);
+        };
+
+        let Step::Converge {
+            staging,
+            condition,
+            max_iterations,
+            len,
+            ..
+        } = &self.steps[pending.step]
+        else {
+            return Err(Error::InvalidStep(format!(
+                "{:?}",
+                self.steps[pending.step]
+            )));
+        };
+
+        let converged = (condition.0)(&staging.slice(..).get_mapped_range());
+        staging.unmap();
+
+        let done = converged || pending.iterations >= *max_iterations;
+        let group = pending.step + 1..pending.step + 1 + len;
+
+        self.command_encoder = Some(
+            self.render_device
+                .create_command_encoder(&CommandEncoderDescriptor { label: None }),
+        );
+
+        let converging = if done {
+            self.iterations.insert(pending.step, pending.iterations);
+            self.run_steps(group.end..self.steps.len())?
+        } else {
+            self.run_steps(group)?;
+            self.copy_flag(pending.step)?;
+            pending.iterations += 1;
+            self.pending_convergence = Some(pending);
+            true
+        };
+
+        self.submit_batch(converging)?;
+
+        Ok(true)
+    }
+
+    /// Poll the submitted work, going through the batches of the pending convergences.
+    ///
+    /// Returns true once everything is done and the staging buffers are mapped.
+    fn poll_batches(&mut self) -> bool {
+        loop {
+            if !self.poll() {
+                return false;
+            }
+
+            match self.next_batch() {
+                Ok(true) => {}
+                Ok(false) => return true,
+                Err(err) => panic!("{:?}", err),
+            }
+        }
+    }
+
+    #[inline]
+    fn read_staging_buffers(&mut self) -> Result<&mut Self> {
+        for (name, staging_buffer) in &mut self.staging_buffers {
+            let Some(encoder) = &mut self.command_encoder else {
+                return Err(Error::EncoderIsNone);
+            };
+
+            if let Some(texture) = self.textures.get(name) {
+                let Some(bytes_per_row) = texture.padded_bytes_per_row() else {
+                    return Err(texture.uncopyable(name));
+                };
+                let size = texture.texture.size();
+                encoder.copy_texture_to_buffer(
+                    texture.texture.as_image_copy(),
+                    ImageCopyBuffer {
+                        buffer: &staging_buffer.buffer,
+                        layout: ImageDataLayout {
+                            offset: 0,
+                            bytes_per_row: Some(bytes_per_row),
+                            rows_per_image: Some(size.height),
+                        },
+                    },
+                    size,
+                );
+                continue;
+            }
+
+            let Some(buffer) = self.buffers.get(name) else {
+                return Err(Error::BufferNotFound(name.to_owned()));
+            };
+
+            let range = staging_buffer
+                .range
+                .clone()
+                .unwrap_or(0..staging_buffer.buffer.size());
+            let aligned = staging_buffer.aligned(&range);
+            encoder.copy_buffer_to_buffer(
+                buffer,
+                aligned.start,
+                &staging_buffer.buffer,
+                aligned.start,
+                aligned.end - aligned.start,
+            );
+            staging_buffer.copied = range;
+        }
+        Ok(self)
+    }
+
+    #[inline]
+    fn map_staging_buffers(&mut self) -> &mut Self {
+        for (_, staging_buffer) in self.staging_buffers.iter_mut() {
+            let aligned = staging_buffer.aligned(&staging_buffer.copied);
+            let read_buffer_slice = staging_buffer.buffer.slice(aligned);
+
+            read_buffer_slice.map_async(wgpu::MapMode::Read, |result| {
+                if let Some(err) = result.err() {
+                    panic!("{}", err.to_string());
+                }
+            });
+        }
+        self
+    }
+
+    /// Read data from `target` staging buffer, return raw bytes
+    #[inline]
+    pub fn try_read_raw<'a>(&'a self, target: &str) -> Result<(impl Deref<Target = [u8]> + 'a)> {
+        let Some(staging_buffer) = &self.staging_buffers.get(target) else {
+            return Err(Error::StagingBufferNotFound(target.to_owned()));
+        };
+
+        if !staging_buffer.mapped {
+            return Err(Error::StagingBufferNotMapped(target.to_owned()));
+        }
+
+        let copied = &staging_buffer.copied;
+        let aligned = staging_buffer.aligned(copied);
+        let view = staging_buffer
+            .buffer
+            .slice(aligned.clone())
+            .get_mapped_range();
+        let start = (copied.start - aligned.start) as usize;
+
+        Ok(MappedBytes {
+            view,
+            range: start..start + (copied.end - copied.start) as usize,
+        })
+    }
+
+    /// Read data from `target` staging buffer, return raw bytes
+    /// Panics on error.
+    #[inline]
+    pub fn read_raw<'a>(&'a self, target: &str) -> (impl Deref<Target = [u8]> + 'a) {
+        self.try_read_raw(target).unwrap()
+    }
+
+    /// Try Read data from `target` staging buffer, return a single `B: Pod`
+    #[inline]
+    pub fn try_read<B: AnyBitPattern>(&self, target: &str) -> Result<B> {
+        let bytes = self.try_read_raw(target)?;
+        if bytes.len() != std::mem::size_of::<B>() {
+            return Err(Error::SizeMismatch {
+                buffer: target.to_owned(),
+                expected: bytes.len() as u64,
+                actual: std::mem::size_of::<B>() as u64,
+            });
+        }
+
+        Ok(pod_read_unaligned(&bytes))
+    }
+
+    /// Try Read data from `target` staging buffer, return a single `B: Pod`
+    /// In case of error, this function will panic.
+    #[inline]
+    pub fn read<B: AnyBitPattern>(&self, target: &str) -> B {
+        self.try_read(target).unwrap()
+    }
+
+    /// Try Read data from `target` staging buffer, return a vector of `B: Pod`
+    #[inline]
+    pub fn try_read_vec<B: AnyBitPattern>(&self, target: &str) -> Result<Vec<B>> {
+        let bytes = self.try_read_raw(target)?;
+        read_elements(target, &bytes)
+    }
+
+    /// Try Read data from `target` staging buffer, return a vector of `B: Pod`
+    /// In case of error, this function will panic.
+    #[inline]
+    pub fn read_vec<B: AnyBitPattern>(&self, target: &str) -> Vec<B> {
+        self.try_read_vec(target).unwrap()
+    }
+
+    /// Try Read the elements in `range` from `target` staging buffer, return a vector of `B: Pod`
+    ///
+    /// `range` is in elements from the start of the buffer, and must have been copied by the
+    /// last run (see [`Self::set_staging_range`]).
+    #[inline]
+    pub fn try_read_range<B: AnyBitPattern>(
+        &self,
+        target: &str,
+        range: Range<usize>,
+    ) -> Result<Vec<B>> {
+        let Some(staging_buffer) = self.staging_buffers.get(target) else {
+            return Err(Error::StagingBufferNotFound(target.to_owned()));
+        };
+
+        let size = std::mem::size_of::<B>() as u64;
+        let bytes_range = range.start as u64 * size..range.end as u64 * size;
+        let copied = staging_buffer.copied.clone();
+        if bytes_range.start > bytes_range.end
+            || bytes_range.start < copied.start
+            || bytes_range.end > copied.end
+        {
+            return Err(Error::OutOfRange {
+                buffer: target.to_owned(),
+                range: bytes_range,
+                available: copied,
+            });
+        }
+
+        let bytes = self.try_read_raw(target)?;
+        let start = (bytes_range.start - copied.start) as usize;
+        let end = (bytes_range.end - copied.start) as usize;
+        read_elements(target, &bytes[start..end])
+    }
+
+    /// Try Read the elements in `range` from `target` staging buffer, return a vector of `B: Pod`
+    /// In case of error, this function will panic.
+    #[inline]
+    pub fn read_range<B: AnyBitPattern>(&self, target: &str, range: Range<usize>) -> Vec<B> {
+        self.try_read_range(target, range).unwrap()
+    }
+
+    /// Only copy the elements in `range` of `target` buffer to its staging buffer on the next runs,
+    /// instead of the whole buffer.
+    ///
+    /// After these runs, the `read*` functions return the elements in `range` only.
+    pub fn try_set_staging_range<B>(&mut self, target: &str, range: Range<usize>) -> Result<()> {
+        let Some(staging_buffer) = self.staging_buffers.get_mut(target) else {
+            return Err(Error::StagingBufferNotFound(target.to_owned()));
+        };
+
+        let size = std::mem::size_of::<B>() as u64;
+        let bytes_range = range.start as u64 * size..range.end as u64 * size;
+        let available = 0..staging_buffer.buffer.size();
+        if bytes_range.is_empty()
+            || bytes_range.end > available.end
+            || self.textures.contains_key(target)
+        {
+            return Err(Error::OutOfRange {
+                buffer: target.to_owned(),
+                range: bytes_range,
+                available,
+            });
+        }
+
+        staging_buffer.range = Some(bytes_range);
+
+        Ok(())
+    }
+
+    /// Only copy the elements in `range` of `target` buffer to its staging buffer on the next runs.
+    /// In case of error, this function will panic.
+    pub fn set_staging_range<B>(&mut self, target: &str, range: Range<usize>) {
+        self.try_set_staging_range::<B>(target, range).unwrap()
+    }
+
+    /// Copy the whole `target` buffer to its staging buffer on the next runs again.
+    pub fn reset_staging_range(&mut self, target: &str) {
+        if let Some(staging_buffer) = self.staging_buffers.get_mut(target) {
+            staging_buffer.range = None;
+        }
+    }
+
+    /// Try Read texels from `target` staging texture, return a vector of `B: Pod`
+    ///
+    /// Texels are returned row by row, layer by layer, without any row padding.
+    #[inline]
+    pub fn try_read_texture_vec<B: AnyBitPattern>(&self, target: &str) -> Result<Vec<B>> {
+        let Some(texture) = self.textures.get(target) else {
+            return Err(Error::TextureNotFound(target.to_owned()));
+        };
+
+        let (Some(padded_bytes_per_row), Some(unpadded_bytes_per_row)) = (
+            texture.padded_bytes_per_row(),
+            texture.unpadded_bytes_per_row(),
+        ) else {
+            return Err(texture.uncopyable(target));
+        };
+        let padded_bytes_per_row = padded_bytes_per_row as usize;
+        let unpadded_bytes_per_row = unpadded_bytes_per_row as usize;
+
+        let bytes = self.try_read_raw(target)?;
+        let rows = bytes.len() / padded_bytes_per_row;
+        let mut texels = Vec::with_capacity(rows * unpadded_bytes_per_row);
+        for row in bytes.chunks_exact(padded_bytes_per_row) {
+            texels.extend_from_slice(&row[..unpadded_bytes_per_row]);
+        }
+
+        read_elements(target, &texels)
+    }
+
+    /// Try Read texels from `target` staging texture, return a vector of `B: Pod`
+    /// In case of error, this function will panic.
+    #[inline]
+    pub fn read_texture_vec<B: AnyBitPattern>(&self, target: &str) -> Vec<B> {
+        self.try_read_texture_vec(target).unwrap()
+    }
+
+    /// Write data to `target` buffer.
+    #[inline]
+    pub fn try_write<T: NoUninit>(&mut self, target: &str, data: &T) -> Result<()> {
+        self.write_bytes(target, 0, bytes_of(data))
+    }
+
+    /// Write data to `target` buffer.
+    /// In case of error, this function will panic.
+    #[inline]
+    pub fn write<T: NoUninit>(&mut self, target: &str, data: &T) {
+        self.try_write(target, data).unwrap()
+    }
+
+    /// Write data to `target` buffer.
+    #[inline]
+    pub fn try_write_slice<T: NoUninit>(&mut self, target: &str, data: &[T]) -> Result<()> {
+        self.write_bytes(target, 0, cast_slice(data))
+    }
+
+    /// Write data to `target` buffer.
+    /// In case of error, this function will panic.
+    #[inline]
+    pub fn write_slice<T: NoUninit>(&mut self, target: &str, data: &[T]) {
+        self.try_write_slice(target, data).unwrap()
+    }
+
+    /// Write data to `target` buffer, starting at the element `element_offset`.
+    #[inline]
+    pub fn try_write_slice_at<T: NoUninit>(
+        &mut self,
+        target: &str,
+        element_offset: usize,
+        data: &[T],
+    ) -> Result<()> {
+        let offset = (element_offset * std::mem::size_of::<T>()) as u64;
+        self.write_bytes(target, offset, cast_slice(data))
+    }
+
+    /// Write data to `target` buffer, starting at the element `element_offset`.
+    /// In case of error, this function will panic.
+    #[inline]
+    pub fn write_slice_at<T: NoUninit>(&mut self, target: &str, element_offset: usize, data: &[T]) {
+        self.try_write_slice_at(target, element_offset, data)
+            .unwrap()
+    }
+
+    /// Try Read data from the staging buffer of `handle`, return a vector of `T`,
+    /// decoded with encase like [`Self::try_read_shader_type`].
+    #[inline]
+    pub fn try_read_handle<T: ShaderSize + CreateFrom>(
+        &self,
+        handle: &BufferHandle<T, Staging>,
+    ) -> Result<Vec<T>> {
+        self.try_read_shader_type(handle.name())
+    }
+
+    /// Try Read data from the staging buffer of `handle`, return a vector of `T`,
+    /// decoded with encase like [`Self::try_read_shader_type`].
+    /// In case of error, this function will panic.
+    #[inline]
+    pub fn read_handle<T: ShaderSize + CreateFrom>(
+        &self,
+        handle: &BufferHandle<T, Staging>,
+    ) -> Vec<T> {
+        self.try_read_handle(handle).unwrap()
+    }
+
+    /// Write data to the buffer of `handle`, encoded with encase like [`Self::try_write_shader_type`].
+    #[inline]
+    pub fn try_write_handle<T: ShaderSize + WriteInto, K: ArrayKind>(
+        &mut self,
+        handle: &BufferHandle<T, K>,
+        data: &[T],
+    ) -> Result<()> {
+        self.try_write_shader_type(handle.name(), data)
+    }
+
+    /// Write data to the buffer of `handle`, encoded with encase like [`Self::try_write_shader_type`].
+    /// In case of error, this function will panic.
+    #[inline]
+    pub fn write_handle<T: ShaderSize + WriteInto, K: ArrayKind>(
+        &mut self,
+        handle: &BufferHandle<T, K>,
+        data: &[T],
+    ) {
+        self.try_write_handle(handle, data).unwrap()
+    }
+
+    /// Write `data` to the uniform buffer of `handle`, following the WGSL uniform layout rules.
+    #[inline]
+    pub fn try_write_uniform_handle<T: ShaderType + WriteInto>(
+        &mut self,
+        handle: &BufferHandle<T, Uniform>,
+        data: &T,
+    ) -> Result<()> {
+        self.try_write_shader_type(handle.name(), data)
+    }
+
+    /// Write `data` to the uniform buffer of `handle`, following the WGSL uniform layout rules.
+    /// In case of error, this function will panic.
+    #[inline]
+    pub fn write_uniform_handle<T: ShaderType + WriteInto>(
+        &mut self,
+        handle: &BufferHandle<T, Uniform>,
+        data: &T,
+    ) {
+        self.try_write_uniform_handle(handle, data).unwrap()
+    }
+
+    /// Read data from `target` staging buffer, decoded with encase following the WGSL storage layout rules.
+    ///
+    /// Unlike [`Self::try_read`], this handles padding (e.g. `vec3` members) and runtime-sized arrays.
+    #[inline]
+    pub fn try_read_shader_type<T: ShaderType + CreateFrom>(&self, target: &str) -> Result<T> {
+        let bytes = self.try_read_raw(target)?;
+
+        StorageBuffer::new(&*bytes)
+            .create()
+            .map_err(|error| Error::Encase {
+                buffer: target.to_owned(),
+                error,
+            })
+    }
+
+    /// Read data from `target` staging buffer, decoded with encase following the WGSL storage layout rules.
+    /// In case of error, this function will panic.
+    #[inline]
+    pub fn read_shader_type<T: ShaderType + CreateFrom>(&self, target: &str) -> T {
+        self.try_read_shader_type(target).unwrap()
+    }
+
+    /// Read data from `target` staging buffer into `value`, reusing its allocations.
+    #[inline]
+    pub fn try_read_shader_type_into<T: ?Sized + ShaderType + ReadFrom>(
+        &self,
+        target: &str,
+        value: &mut T,
+    ) -> Result<()> {
+        let bytes = self.try_read_raw(target)?;
+
+        StorageBuffer::new(&*bytes)
+            .read(value)
+            .map_err(|error| Error::Encase {
+                buffer: target.to_owned(),
+                error,
+            })
+    }
+
+    /// Read data from `target` staging buffer into `value`, reusing its allocations.
+    /// In case of error, this function will panic.
+    #[inline]
+    pub fn read_shader_type_into<T: ?Sized + ShaderType + ReadFrom>(
+        &self,
+        target: &str,
+        value: &mut T,
+    ) {
+        self.try_read_shader_type_into(target, value).unwrap()
+    }
+
+    /// Write data to `target` buffer, encoded with encase following the uniform
+    /// or storage layout rules, depending on how the buffer was declared.
+    #[inline]
+    pub fn try_write_shader_type<T: ?Sized + ShaderType + WriteInto>(
+        &mut self,
+        target: &str,
+        data: &T,
+    ) -> Result<()> {
+        let encoded = match self.buffer_kinds.get(target) {
+            Some(BufferKind::Uniform) => {
+                let mut encoded = UniformBuffer::new(Vec::new());
+                encoded.write(data).map(|_| encoded.into_inner())
+            }
+            _ => {
+                let mut encoded = StorageBuffer::new(Vec::new());
+                encoded.write(data).map(|_| encoded.into_inner())
+            }
+        }
+        .map_err(|error| Error::Encase {
+            buffer: target.to_owned(),
+            error,
+        })?;
+
+        self.write_bytes(target, 0, &encoded)
+    }
+
+    /// The step at `step`, either its index in the builder or its label.
+    fn step_mut(&mut self, step: StepId) -> Option<&mut Step> {
+        let index = self.step_index(step)?;
+        self.steps.get_mut(index)
+    }
+
+    /// The index of `step` in `steps`, if it exists.
+    fn step_index(&self, step: StepId) -> Option<usize> {
+        let index = match step {
+            StepId::Index(index) => index,
+            StepId::Label(label) => *self.step_labels.get(label)?,
+        };
+
+        (index < self.steps.len()).then_some(index)
+    }
+
+    /// Skip the step at `step`, its index in the builder or its label, on the next runs if `enabled`
+    /// is false, and run it again otherwise.
+    pub fn try_set_step_enabled<'a>(
+        &mut self,
+        step: impl Into<StepId<'a>>,
+        enabled: bool,
+    ) -> Result<()> {
+        let step = step.into();
+        let Some(index) = self.step_index(step) else {
+            return Err(Error::InvalidStep(format!("{step}")));
+        };
+
+        if enabled {
+            self.disabled_steps.remove(&index);
+        } else {
+            self.disabled_steps.insert(index);
+        }
+
+        Ok(())
+    }
+
+    /// Skip the step at `step`, its index in the builder or its label, on the next runs if `enabled`
+    /// is false, and run it again otherwise. In case of error, this function will panic.
+    pub fn set_step_enabled<'a>(&mut self, step: impl Into<StepId<'a>>, enabled: bool) {
+        self.try_set_step_enabled(step, enabled).unwrap()
+    }
+
+    /// Set how many times the steps added in the `add_repeat` at `step`, its index in the builder or
+    /// its label, run.
+    pub fn try_set_repeat_count<'a>(
+        &mut self,
+        step: impl Into<StepId<'a>>,
+        count: u32,
+    ) -> Result<()> {
+        let step = step.into();
+        let Some(Step::Repeat { count: repeat, .. }) = self.step_mut(step) else {
+            return Err(Error::InvalidStep(format!("{step}")));
+        };
+
+        *repeat = count;
+
+        Ok(())
+    }
+
+    /// Set how many times the steps added in the `add_repeat` at `step`, its index in the builder or
+    /// its label, run. In case of error, this function will panic.
+    pub fn set_repeat_count<'a>(&mut self, step: impl Into<StepId<'a>>, count: u32) {
+        self.try_set_repeat_count(step, count).unwrap()
+    }
+
+    /// The number of iterations the group added with `add_converge` at `step`, its index in the
+    /// builder or its label, took to converge on the last run, or 0 if it never ran.
+    pub fn try_iterations<'a>(&self, step: impl Into<StepId<'a>>) -> Result<u32> {
+        let step = step.into();
+        let Some(index) = self
+            .step_index(step)
+            .filter(|index| matches!(self.steps[*index], Step::Converge { .. }))
+        else {
+            return Err(Error::InvalidStep(format!("{step}")));
+        };
+
+        Ok(self.iterations.get(&index).copied().unwrap_or(0))
+    }
+
+    /// The number of iterations the group added with `add_converge` at `step`, its index in the
+    /// builder or its label, took to converge on the last run, or 0 if it never ran.
+    /// In case of error, this function will panic.
+    pub fn iterations<'a>(&self, step: impl Into<StepId<'a>>) -> u32 {
+        self.try_iterations(step).unwrap()
+    }
+
+    /// Check if the step at `step`, its index in the builder or its label, runs.
+    /// Returns false if there is no such step.
+    pub fn step_enabled<'a>(&self, step: impl Into<StepId<'a>>) -> bool {
+        self.step_index(step.into())
+            .is_some_and(|index| !self.disabled_steps.contains(&index))
+    }
+
+    /// Set the push constants of the compute pass at `step`, its index in the builder or its label.
+    ///
+    /// `bytes` are set at offset 0, and must fit in the push constant ranges of the shader.
+    pub fn try_set_push_constants<'a>(
+        &mut self,
+        step: impl Into<StepId<'a>>,
+        bytes: &[u8],
+    ) -> Result<()> {
+        let step = step.into();
+        let Some(Step::ComputePass(compute_pass)) = self.step_mut(step) else {
+            return Err(Error::InvalidStep(format!("{step}")));
+        };
+
+        check_push_constants(compute_pass, bytes)?;
+        compute_pass.push_constants = bytes.to_vec();
+
+        Ok(())
+    }
+
+    /// Set the push constants of the compute pass at `step`, its index in the builder or its label.
+    /// In case of error, this function will panic.
+    pub fn set_push_constants<'a>(&mut self, step: impl Into<StepId<'a>>, bytes: &[u8]) {
+        self.try_set_push_constants(step, bytes).unwrap()
+    }
+
+    /// Set the number of workgroups the compute pass at `step` dispatches, `step` being its index
+    /// in the builder or its label. An indirect pass switches to dispatching `workgroups`.
+    pub fn try_set_workgroups<'a>(
+        &mut self,
+        step: impl Into<StepId<'a>>,
+        workgroups: [u32; 3],
+    ) -> Result<()> {
+        let step = step.into();
+        let Some(Step::ComputePass(compute_pass)) = self.step_mut(step) else {
+            return Err(Error::InvalidStep(format!("{step}")));
+        };
+
+        compute_pass.dispatch = Dispatch::Workgroups(workgroups);
+
+        Ok(())
+    }
+
+    /// Set the number of workgroups the compute pass at `step` dispatches, `step` being its index
+    /// in the builder or its label. In case of error, this function will panic.
+    pub fn set_workgroups<'a>(&mut self, step: impl Into<StepId<'a>>, workgroups: [u32; 3]) {
+        self.try_set_workgroups(step, workgroups).unwrap()
+    }
+
+    /// Write `bytes` at `offset` in `target` buffer, growing it first if allowed.
+    ///
+    /// Everything is checked here, so that wgpu never panics on the queue.
+    fn write_bytes(&mut self, target: &str, offset: u64, bytes: &[u8]) -> Result<()> {
+        let Some(buffer) = self.buffers.get(target) else {
+            return Err(Error::BufferNotFound(target.to_owned()));
+        };
+
+        let size = bytes.len() as u64;
+        if !offset.is_multiple_of(COPY_BUFFER_ALIGNMENT)
+            || !size.is_multiple_of(COPY_BUFFER_ALIGNMENT)
+        {
+            return Err(Error::Misaligned {
+                buffer: target.to_owned(),
+                offset,
+                size,
+                alignment: COPY_BUFFER_ALIGNMENT,
+            });
+        }
+
+        let end = offset + size;
+        if end > buffer.size() {
+            if !self.grow_on_write {
+                return Err(Error::SizeMismatch {
+                    buffer: target.to_owned(),
+                    expected: buffer.size(),
+                    actual: end,
+                });
+            }
+
+            // Only what comes before `offset` survives the write
+            self.try_resize_buffer(target, end, offset > 0)?;
+        }
+
+        self.render_queue
+            .write_buffer(&self.buffers[target], offset, bytes);
+        self.inputs_changed = true;
+
+        Ok(())
+    }
+
+    /// Reallocate `target` buffer, and its staging buffer if it has one, with `new_size` bytes.
+    ///
+    /// If `keep_contents` is set, the start of the old buffer is copied to the new one on the GPU.
+    /// Otherwise the new buffer is zeroed. Following passes bind the new buffer, and the staging
+    /// buffer reads as zeros until the worker runs again. A range set with
+    /// [`Self::set_staging_range`] is kept, clamped to the new size.
+    ///
+    /// Returns [`Error::SizeMismatch`] if `new_size` is below the minimum size the shaders
+    /// binding `target` expect.
+    pub fn try_resize_buffer(
+        &mut self,
+        target: &str,
+        new_size: u64,
+        keep_contents: bool,
+    ) -> Result<()> {
+        let Some(old_buffer) = self.buffers.get(target) else {
+            return Err(Error::BufferNotFound(target.to_owned()));
+        };
+
+        let new_size = new_size.next_multiple_of(COPY_BUFFER_ALIGNMENT);
+        let min_size = self.min_binding_size(target);
+        if new_size < min_size {
+            return Err(Error::SizeMismatch {
+                buffer: target.to_owned(),
+                expected: new_size,
+                actual: min_size,
+            });
+        }
+
+        let new_buffer = self.render_device.create_buffer(&BufferDescriptor {
+            label: Some(target),
+            size: new_size,
+            usage: old_buffer.usage(),
+            mapped_at_creation: false,
+        });
+
+        if keep_contents {
+            let mut encoder = self
+                .render_device
+                .create_command_encoder(&CommandEncoderDescriptor { label: None });
+            let copy_size = old_buffer.size().min(new_size);
+            encoder.copy_buffer_to_buffer(old_buffer, 0, &new_buffer, 0, copy_size);
+            self.render_queue.submit(Some(encoder.finish()));
+        }
+
+        if let Some(staging_buffer) = self.staging_buffers.get_mut(target) {
+            if staging_buffer.mapped {
+                staging_buffer.buffer.unmap();
+            }
+
+            let range = staging_buffer
+                .range
+                .take()
+                .map(|range| range.start..range.end.min(new_size))
+                .filter(|range| !range.is_empty());
+            *staging_buffer = StagingBuffer::new(&self.render_device, target, new_size);
+            staging_buffer.range = range;
+        }
+
+        self.buffers.insert(target.to_owned(), new_buffer);
+        self.inputs_changed = true;
+
+        Ok(())
+    }
+
+    /// Reallocate `target` buffer, and its staging buffer if it has one, with `new_size` bytes.
+    /// In case of error, this function will panic.
+    ///
+    /// See [`Self::try_resize_buffer`].
+    pub fn resize_buffer(&mut self, target: &str, new_size: u64, keep_contents: bool) {
+        self.try_resize_buffer(target, new_size, keep_contents)
+            .unwrap()
+    }
+
+    /// Write data to `target` buffer, encoded with encase following the uniform
+    /// or storage layout rules, depending on how the buffer was declared.
+    /// In case of error, this function will panic.
+    #[inline]
+    pub fn write_shader_type<T: ?Sized + ShaderType + WriteInto>(
+        &mut self,
+        target: &str,
+        data: &T,
+    ) {
+        self.try_write_shader_type(target, data).unwrap()
+    }
+
+    fn submit(&mut self) -> &mut Self {
+        let encoder = self.command_encoder.take().unwrap();
+        self.render_queue.submit(Some(encoder.finish()));
         self.state = WorkerState::Working {
             start_time: SystemTime::now(),
         };
@@ -374,6 +1771,65 @@ impl<W: ComputeWorker> AppComputeWorker<W> {
         }
     }
 
+    /// Queue a job: once the jobs submitted before it are done, `inputs` are written to the start
+    /// of their buffers and the worker runs, as if `execute()` was called. `output` then reads the
+    /// results of that run, which resolve the returned [`ComputeJob`].
+    ///
+    /// ```
+    /// let job = compute_worker.submit_job(&[("values", cast_slice(&values))], |worker| {
+    ///     worker.read_vec::<f32>("values")
+    /// });
+    /// ```
+    pub fn submit_job<T: Send + 'static>(
+        &mut self,
+        inputs: &[(&str, &[u8])],
+        output: impl FnOnce(&Self) -> T + Send + Sync + 'static,
+    ) -> ComputeJob<T> {
+        let inputs = inputs
+            .iter()
+            .map(|(name, bytes)| (String::from(*name), bytes.to_vec()))
+            .collect();
+        let (job, compute_job) = PendingJob::new(inputs, output);
+        self.jobs.push_back(job);
+        compute_job
+    }
+
+    /// Write the inputs of the next job and request a run for it, unless a job is already running.
+    /// Jobs whose inputs can't be written fail right away.
+    fn start_next_job(&mut self) {
+        if self.current_job.is_some() {
+            return;
+        }
+
+        while let Some(job) = self.jobs.pop_front() {
+            let written = job
+                .inputs
+                .iter()
+                .try_for_each(|(name, bytes)| self.write_bytes(name, 0, bytes));
+
+            match written {
+                Ok(()) => {
+                    self.current_job = Some(job);
+                    self.execute();
+                    return;
+                }
+                Err(err) => (job.complete)(self, Err(err)),
+            }
+        }
+    }
+
+    /// Check if the worker has no run in progress, so `execute()` would run it right away.
+    #[inline]
+    pub fn idle(&self) -> bool {
+        !matches!(self.state, WorkerState::Working { start_time: _ })
+    }
+
+    /// How many runs the worker completed.
+    #[inline]
+    pub fn runs(&self) -> u64 {
+        self.runs
+    }
+
     /// Check if the worker is ready to be read from.
     #[inline]
     pub fn ready(&self) -> bool {
@@ -386,63 +1842,265 @@ impl<W: ComputeWorker> AppComputeWorker<W> {
         match self.run_mode {
             RunMode::Continuous => {}
             RunMode::OneShot(_) => self.run_mode = RunMode::OneShot(true),
+            RunMode::EveryNFrames(_) | RunMode::Every(_) | RunMode::OnInputChange => {
+                self.run_requested = true
+            }
         }
     }
 
     #[inline]
     fn ready_to_execute(&self) -> bool {
-        (!matches!(self.state, WorkerState::Working { start_time: _ }))
-            && (self.run_mode != RunMode::OneShot(false))
+        let requested = match self.run_mode {
+            RunMode::Continuous => true,
+            RunMode::OneShot(requested) => requested,
+            RunMode::EveryNFrames(_) | RunMode::Every(_) => self.run_requested,
+            RunMode::OnInputChange => self.run_requested || self.inputs_changed,
+        };
+
+        self.idle() && requested
+    }
+
+    /// Count the frames and time since the last run, for the run modes depending on them.
+    #[inline]
+    fn tick(&mut self, delta: Duration) {
+        match self.run_mode {
+            RunMode::EveryNFrames(frames) => {
+                self.frames += 1;
+                if self.frames >= frames {
+                    self.frames = 0;
+                    self.run_requested = true;
+                }
+            }
+            RunMode::Every(_) => {
+                if self.timer.tick(delta).just_finished() {
+                    self.run_requested = true;
+                }
+            }
+            RunMode::Continuous | RunMode::OneShot(_) | RunMode::OnInputChange => {}
+        }
     }
 
-    pub(crate) fn run(mut worker: ResMut<Self>) {
+    pub(crate) fn run(
+        mut worker: ResMut<Self>,
+        time: Res<Time>,
+        mut finished: EventWriter<ComputeFinished<W>>,
+    ) {
         if worker.ready() {
             worker.state = WorkerState::Available;
         }
 
-        if worker.ready_to_execute() {
-            // Workaround for interior mutability
-            for i in 0..worker.steps.len() {
-                let result = match worker.steps[i] {
-                    Step::ComputePass(_) => worker.dispatch(i),
-                    Step::Swap(_, _) => worker.swap(i),
-                };
+        if let Some(err) = worker.invalid.clone() {
+            worker.fail_jobs(err);
+            return;
+        }
 
-                if let Err(err) = result {
-                    match err {
-                        Error::PipelineNotReady => return,
-                        _ => panic!("{:?}", err),
-                    }
-                }
+        worker.tick(time.delta());
+
+        if worker.idle() {
+            worker.start_next_job();
+        }
+
+        if worker.ready_to_execute() {
+            match worker.start_run() {
+                Ok(()) => {}
+                Err(Error::PipelineNotReady) => return,
+                Err(err) => panic!("{:?}", err),
             }
+        }
+
+        if matches!(worker.state, WorkerState::Working { start_time: _ }) && worker.poll_batches() {
+            finished.send(worker.complete_run());
+        }
+    }
+
+    /// Record the steps of a run and submit its first batch.
+    fn start_run(&mut self) -> Result<()> {
+        self.gpu_time = Duration::ZERO;
+        let converging = self.run_steps(0..self.steps.len())?;
+        self.run_started = SystemTime::now();
+        self.submit_batch(converging)?;
+        self.run_requested = false;
+        self.inputs_changed = false;
+
+        Ok(())
+    }
+
+    /// Complete a run whose batches are all done, returning the event to send for it.
+    fn complete_run(&mut self) -> ComputeFinished<W> {
+        self.finish_run();
+
+        if let RunMode::OneShot(_) = self.run_mode {
+            self.run_mode = RunMode::OneShot(false);
+        }
+
+        ComputeFinished {
+            run: self.runs,
+            gpu_time: self.gpu_timer.as_ref().map(|_| self.gpu_time),
+            wall_time: self.run_started.elapsed().unwrap_or_default(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Once the staging buffers are mapped, make them readable and complete the current job.
+    fn finish_run(&mut self) {
+        for (_, staging_buffer) in self.staging_buffers.iter_mut() {
+            // By this the staging buffers would've been mapped.
+            staging_buffer.mapped = true;
+        }
+
+        self.state = WorkerState::FinishedWorking;
+        self.runs += 1;
+        self.command_encoder = Some(
+            self.render_device
+                .create_command_encoder(&CommandEncoderDescriptor { label: None }),
+        );
 
-            worker.read_staging_buffers().unwrap();
-            worker.submit();
-            worker.map_staging_buffers();
+        if let Some(job) = self.current_job.take() {
+            (job.complete)(self, Ok(()));
         }
+    }
 
-        if worker.run_mode != RunMode::OneShot(false) && worker.poll() {
-            for (_, staging_buffer) in worker.staging_buffers.iter_mut() {
-                // By this the staging buffers would've been mapped.
-                staging_buffer.mapped = true;
+    /// Wait for the submitted work, going through the batches of the pending convergences.
+    fn wait_batches(&mut self) -> Result<()> {
+        loop {
+            self.render_device
+                .wgpu_device()
+                .poll(wgpu::MaintainBase::Wait);
+
+            if !self.next_batch()? {
+                break;
             }
+        }
+        self.submission_queue_processed = false;
+
+        Ok(())
+    }
+
+    /// Run the worker to completion, blocking until its staging buffers can be read.
+    ///
+    /// The shaders of the worker are loaded and its pipelines compiled first if needed, and a run
+    /// already in progress is waited for. A [`ComputeFinished`] event is sent for every completed
+    /// run. Returns [`Error::PipelineNotReady`] if the pipelines still aren't compiled after
+    /// 30 seconds, see [`Self::run_blocking_with_timeout`].
+    ///
+    /// ```
+    /// fn exclusive_system(world: &mut World) {
+    ///     world.resource_scope(|world, mut compute_worker: Mut<AppComputeWorker<MyWorker>>| {
+    ///         compute_worker.run_blocking(world).unwrap();
+    ///         let values = compute_worker.read_vec::<f32>("values");
+    ///     });
+    /// }
+    /// ```
+    pub fn run_blocking(&mut self, world: &mut World) -> Result<()> {
+        self.run_blocking_with_timeout(world, PIPELINE_TIMEOUT)
+    }
+
+    /// Same as [`Self::run_blocking`], waiting at most `timeout` for the shaders to load and the
+    /// pipelines to compile.
+    pub fn run_blocking_with_timeout(
+        &mut self,
+        world: &mut World,
+        timeout: Duration,
+    ) -> Result<()> {
+        self.validation()?;
+        self.compile_pipelines(world, timeout)?;
+
+        if !self.idle() {
+            self.wait_batches()?;
+            world.send_event(self.complete_run());
+        }
+        self.unmap_staging_buffers();
 
-            worker.state = WorkerState::FinishedWorking;
-            worker.command_encoder = Some(
-                worker
-                    .render_device
+        if self.command_encoder.is_none() {
+            self.command_encoder = Some(
+                self.render_device
                     .create_command_encoder(&CommandEncoderDescriptor { label: None }),
             );
+        }
+
+        self.start_run()?;
+        self.wait_batches()?;
+        world.send_event(self.complete_run());
+
+        Ok(())
+    }
+
+    /// Load the shaders of the worker and compile its pipelines, blocking until they are ready.
+    ///
+    /// Returns [`Error::ShaderLoadFailed`] as soon as a shader fails to load, and
+    /// [`Error::PipelineNotReady`] if the pipelines aren't ready after `timeout`. With the `webgl`
+    /// feature, shaders only load between frames, so this doesn't wait for them.
+    fn compile_pipelines(&mut self, world: &mut World, timeout: Duration) -> Result<()> {
+        let start = Instant::now();
+
+        loop {
+            handle_internal_asset_events(world);
+            // Kept between calls, so events already read don't queue the same shaders again
+            let shader_events = self
+                .shader_events
+                .get_or_insert_with(|| SystemState::new(world));
+            let (pipeline_cache, shader_assets, events) = shader_events.get_mut(world);
+            extract_shaders(pipeline_cache, shader_assets, events);
+
+            world.resource_mut::<AppPipelineCache>().process_queue();
+            let pipeline_cache = world.resource::<AppPipelineCache>();
+            if let Err(err) = self.load_pipelines(pipeline_cache) {
+                self.invalid = Some(err.clone());
+                return Err(err);
+            }
+
+            if self.pipelines.values().all(Option::is_some) {
+                return Ok(());
+            }
+
+            self.check_shaders_loading(pipeline_cache, world.resource::<AssetServer>())?;
 
-            match worker.run_mode {
-                RunMode::Continuous => {}
-                RunMode::OneShot(_) => worker.run_mode = RunMode::OneShot(false),
+            if cfg!(feature = "webgl") || start.elapsed() >= timeout {
+                return Err(Error::PipelineNotReady);
+            }
+
+            #[cfg(not(feature = "webgl"))]
+            std::thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    /// Check that none of the shaders of the pipelines still compiling failed to load.
+    fn check_shaders_loading(
+        &self,
+        pipeline_cache: &AppPipelineCache,
+        asset_server: &AssetServer,
+    ) -> Result<()> {
+        for (type_path, cached_id) in &self.cached_pipeline_ids {
+            if matches!(self.pipelines.get(type_path), Some(Some(_))) {
+                continue;
+            }
+
+            let Some(shader) = pipeline_cache.get_compute_pipeline_shader(*cached_id) else {
+                continue;
+            };
+
+            let error = match asset_server.load_state(shader) {
+                LoadState::Failed(error) => error.to_string(),
+                _ if asset_server.recursive_dependency_load_state(shader)
+                    == RecursiveDependencyLoadState::Failed =>
+                {
+                    String::from("one of its imports failed to load")
+                }
+                _ => continue,
             };
+
+            return Err(Error::ShaderLoadFailed {
+                shader: type_path.clone(),
+                error,
+            });
         }
+
+        Ok(())
     }
 
-    pub(crate) fn unmap_all(mut worker: ResMut<Self>) {
-        for (_, staging_buffer) in &mut worker.staging_buffers {
+    #[inline]
+    fn unmap_staging_buffers(&mut self) {
+        for (_, staging_buffer) in &mut self.staging_buffers {
             if staging_buffer.mapped {
                 staging_buffer.buffer.unmap();
                 staging_buffer.mapped = false;
@@ -450,12 +2108,48 @@ impl<W: ComputeWorker> AppComputeWorker<W> {
         }
     }
 
+    pub(crate) fn unmap_all(mut worker: ResMut<Self>) {
+        worker.unmap_staging_buffers();
+    }
+
     pub(crate) fn extract_pipelines(
         mut worker: ResMut<Self>,
         pipeline_cache: Res<AppPipelineCache>,
     ) {
-        for (type_path, cached_id) in &worker.cached_pipeline_ids.clone() {
-            let Some(pipeline) = worker.pipelines.get(type_path) else {
+        if let Err(err) = worker.load_pipelines(&pipeline_cache) {
+            if worker.panic_on_invalid {
+                panic!("{err}");
+            }
+
+            error!("{err}");
+            worker.invalid = Some(err);
+        }
+    }
+
+    /// Check that the passes of the worker matched their shaders once their pipelines were
+    /// ready. Returns the first error found otherwise, after which the worker stops running.
+    ///
+    /// Only workers created with `try_build()` report errors here, the ones created with
+    /// `build()` panic instead.
+    pub fn validation(&self) -> Result<()> {
+        match &self.invalid {
+            Some(err) => Err(err.clone()),
+            None => Ok(()),
+        }
+    }
+
+    /// Fail the current and queued jobs with `err`.
+    fn fail_jobs(&mut self, err: Error) {
+        while let Some(job) = self.current_job.take().or_else(|| self.jobs.pop_front()) {
+            (job.complete)(self, Err(err.clone()));
+        }
+    }
+
+    /// Take the pipelines of the worker that are ready in `pipeline_cache`, and check the passes
+    /// using them against their shader.
+    fn load_pipelines(&mut self, pipeline_cache: &AppPipelineCache) -> Result<()> {
+        for (type_path, cached_id) in &self.cached_pipeline_ids.clone() {
+            let Some(pipeline) = self.pipelines.get(type_path) else {
                 continue;
             };
 
@@ -465,14 +2159,384 @@ impl<W: ComputeWorker> AppComputeWorker<W> {
 
             let cached_id = *cached_id;
 
-            worker.pipelines.insert(
-                type_path.clone(),
-                pipeline_cache.get_compute_pipeline(cached_id).cloned(),
-            );
+            let Some(pipeline) = pipeline_cache.get_compute_pipeline(cached_id) else {
+                continue;
+            };
+
+            self.pipelines
+                .insert(type_path.clone(), Some(pipeline.clone()));
+
+            if let Some(reflection) = pipeline_cache.get_compute_pipeline_reflection(cached_id) {
+                self.reflections
+                    .insert(type_path.clone(), reflection.clone());
+            }
+
+            self.resolve_reflected_vars(type_path)?;
+            self.validate_passes(type_path)?;
+        }
+
+        Ok(())
+    }
+
+    /// Check every variable of the passes using `shader_type_path` against the globals of its shader.
+    fn validate_passes(&self, shader_type_path: &str) -> Result<()> {
+        // SPIR-V shaders cannot be reflected, wgpu will validate them when dispatching
+        let Some(reflection) = self.reflections.get(shader_type_path) else {
+            let unsized_elements = self.steps.iter().any(|step| {
+                matches!(step, Step::ComputePass(compute_pass)
+                    if compute_pass.shader_type_path == shader_type_path
+                        && matches!(compute_pass.dispatch, Dispatch::Elements(_))
+                        && compute_pass.workgroup_size.is_none())
+            });
+            if unsized_elements {
+                return Err(Error::ReflectionUnavailable(shader_type_path.to_owned()));
+            }
+
+            return Ok(());
+        };
+
+        for step in &self.steps {
+            let Step::ComputePass(compute_pass) = step else {
+                continue;
+            };
+
+            if compute_pass.shader_type_path != shader_type_path {
+                continue;
+            }
+
+            if let Some(group_count) = self.bind_group_count(compute_pass) {
+                check_bind_groups(compute_pass, group_count)?;
+            }
+
+            for var in &compute_pass.vars {
+                let Some(global) = reflection
+                    .globals
+                    .iter()
+                    .find(|global| global.group == var.group && global.binding == var.binding)
+                else {
+                    return Err(Error::GlobalNotFound {
+                        shader: shader_type_path.to_owned(),
+                        name: var.name.clone(),
+                        group: var.group,
+                        binding: var.binding,
+                    });
+                };
+
+                self.validate_var(shader_type_path, &reflection.module, &var.name, global)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Largest minimum binding size of the reflected globals `name` is bound to, 0 if unknown.
+    fn min_binding_size(&self, name: &str) -> u64 {
+        self.steps
+            .iter()
+            .filter_map(|step| match step {
+                Step::ComputePass(compute_pass) => Some(compute_pass),
+                _ => None,
+            })
+            .flat_map(|compute_pass| {
+                let reflection = self.reflections.get(&compute_pass.shader_type_path);
+                compute_pass
+                    .vars
+                    .iter()
+                    .filter(move |var| var.name == name)
+                    .filter_map(move |var| {
+                        reflection?.globals.iter().find(|global| {
+                            global.group == var.group && global.binding == var.binding
+                        })
+                    })
+            })
+            .map(|global| global.min_binding_size)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Number of bind groups in the pipeline layout of `compute_pass`, if known: the layouts
+    /// its shader declares, or the groups of its reflected globals.
+    fn bind_group_count(&self, compute_pass: &ComputePass) -> Option<u32> {
+        if compute_pass.layout_count > 0 {
+            return Some(compute_pass.layout_count);
+        }
+
+        let reflection = self.reflections.get(&compute_pass.shader_type_path)?;
+        Some(
+            reflection
+                .globals
+                .last()
+                .map_or(0, |global| global.group + 1),
+        )
+    }
+
+    fn validate_var(
+        &self,
+        shader_type_path: &str,
+        module: &Module,
+        name: &str,
+        global: &ReflectedGlobal,
+    ) -> Result<()> {
+        let mismatch = |resource: String| Error::BindingMismatch {
+            shader: shader_type_path.to_owned(),
+            name: name.to_owned(),
+            global: global.kind.to_string(),
+            resource,
+        };
+
+        if let Some(buffer) = self.buffers.get(name) {
+            if let Some(kind) = self.buffer_kinds.get(name) {
+                let compatible = matches!(
+                    (global.kind, kind),
+                    (GlobalKind::Uniform, BufferKind::Uniform)
+                        | (
+                            GlobalKind::Storage { read_only: true },
+                            BufferKind::Storage | BufferKind::RwStorage
+                        )
+                        | (
+                            GlobalKind::Storage { read_only: false },
+                            BufferKind::RwStorage
+                        )
+                );
+
+                // Buffers added with `add_storage` were bound to read_write globals before
+                // passes were validated, and wgpu accepts them
+                if (global.kind, kind)
+                    == (
+                        GlobalKind::Storage { read_only: false },
+                        &BufferKind::Storage,
+                    )
+                {
+                    warn!(
+                        "Shader {shader_type_path} expects a {} for {name}, but {name} is a {kind}. \
+                        Add it with `add_rw_storage` instead of `add_storage`.",
+                        global.kind
+                    );
+                } else if !compatible {
+                    return Err(mismatch(kind.to_string()));
+                }
+            }
+
+            // A layout mismatch usually explains a size mismatch, so report it first
+            if let Some(layout) = self.buffer_layouts.get(name) {
+                layout.check(name, module, global.ty)?;
+            }
+
+            if buffer.size() < global.min_binding_size {
+                return Err(Error::BufferTooSmall {
+                    shader: shader_type_path.to_owned(),
+                    name: name.to_owned(),
+                    size: buffer.size(),
+                    min_size: global.min_binding_size,
+                });
+            }
+
+            return Ok(());
+        }
+
+        if let Some(texture) = self.textures.get(name) {
+            let compatible = match (global.kind, texture.access) {
+                // Storage textures can be sampled too
+                (GlobalKind::SampledTexture, _) => true,
+                (GlobalKind::StorageTexture { access }, Some(texture_access)) => {
+                    let load = access.contains(StorageAccess::LOAD);
+                    let store = access.contains(StorageAccess::STORE);
+                    match texture_access {
+                        StorageTextureAccess::ReadOnly => load && !store,
+                        StorageTextureAccess::WriteOnly => store && !load,
+                        StorageTextureAccess::ReadWrite => true,
+                    }
+                }
+                _ => false,
+            };
+
+            if !compatible {
+                return Err(mismatch(match texture.access {
+                    Some(access) => format!("{access:?} storage texture"),
+                    None => "sampled texture".to_owned(),
+                }));
+            }
+
+            return Ok(());
+        }
+
+        if self.samplers.contains_key(name) {
+            if global.kind != GlobalKind::Sampler {
+                return Err(mismatch("sampler".to_owned()));
+            }
+
+            return Ok(());
         }
+
+        Err(Error::BufferNotFound(name.to_owned()))
+    }
+
+    /// Bind the globals of `shader_type_path` to the buffers, textures and samplers
+    /// of the same name, for passes added with `add_pass_auto()`.
+    fn resolve_reflected_vars(&mut self, shader_type_path: &str) -> Result<()> {
+        for step in &mut self.steps {
+            let Step::ComputePass(compute_pass) = step else {
+                continue;
+            };
+
+            if !compute_pass.reflect_vars || compute_pass.shader_type_path != shader_type_path {
+                continue;
+            }
+
+            let Some(reflection) = self.reflections.get(shader_type_path) else {
+                return Err(Error::ReflectionUnavailable(shader_type_path.to_owned()));
+            };
+
+            let (matched, unmatched): (Vec<_>, Vec<_>) =
+                reflection.globals.iter().partition(|global| {
+                    self.buffers.contains_key(&global.name)
+                        || self.textures.contains_key(&global.name)
+                        || self.samplers.contains_key(&global.name)
+                });
+
+            if !unmatched.is_empty() {
+                return Err(Error::UnmatchedGlobals {
+                    shader: shader_type_path.to_owned(),
+                    globals: unmatched
+                        .iter()
+                        .map(|global| {
+                            format!(
+                                "{} (@group({}) @binding({}))",
+                                global.name, global.group, global.binding
+                            )
+                        })
+                        .collect(),
+                });
+            }
+
+            compute_pass.vars = matched
+                .iter()
+                .map(|global| PassVar {
+                    group: global.group,
+                    binding: global.binding,
+                    name: global.name.clone(),
+                })
+                .collect();
+        }
+
+        Ok(())
     }
 
     pub fn get_buffer(&self, target: &str) -> Option<&Buffer> {
         self.buffers.get(target)
     }
+
+    pub fn get_texture(&self, target: &str) -> Option<&Texture> {
+        self.textures.get(target).map(|texture| &texture.texture)
+    }
+
+    pub fn get_texture_view(&self, target: &str) -> Option<&TextureView> {
+        self.textures.get(target).map(|texture| &texture.view)
+    }
+
+    pub fn get_sampler(&self, target: &str) -> Option<&Sampler> {
+        self.samplers.get(target)
+    }
+}
+
+/// Copy `bytes` of `target` into a vector of `B`, whatever the alignment of `bytes`.
+fn read_elements<B: AnyBitPattern>(target: &str, bytes: &[u8]) -> Result<Vec<B>> {
+    let size = std::mem::size_of::<B>();
+    if size == 0 {
+        return Ok(Vec::new());
+    }
+
+    if !bytes.len().is_multiple_of(size) {
+        return Err(Error::Misaligned {
+            buffer: target.to_owned(),
+            offset: 0,
+            size: bytes.len() as u64,
+            alignment: size as u64,
+        });
+    }
+
+    Ok(bytes.chunks_exact(size).map(pod_read_unaligned).collect())
+}
+
+/// The ranges copied by a copy step from `src` to `dst`, checked against the size of both buffers.
+pub(crate) fn copy_ranges(
+    src: &str,
+    src_buffer: &Buffer,
+    dst: &str,
+    dst_buffer: &Buffer,
+    ranges: &[(u64, u64, u64)],
+) -> Result<Vec<(u64, u64, u64)>> {
+    // wgpu can't copy a buffer into itself
+    if src == dst || src_buffer.global_id() == dst_buffer.global_id() {
+        return Err(Error::CopyToSelf {
+            src: src.to_owned(),
+            dst: dst.to_owned(),
+        });
+    }
+
+    let ranges = if ranges.is_empty() {
+        vec![(0, 0, src_buffer.size())]
+    } else {
+        ranges.to_vec()
+    };
+
+    for &(src_offset, dst_offset, size) in &ranges {
+        for (name, buffer, offset) in [(src, src_buffer, src_offset), (dst, dst_buffer, dst_offset)]
+        {
+            if !offset.is_multiple_of(COPY_BUFFER_ALIGNMENT)
+                || !size.is_multiple_of(COPY_BUFFER_ALIGNMENT)
+            {
+                return Err(Error::Misaligned {
+                    buffer: name.to_owned(),
+                    offset,
+                    size,
+                    alignment: COPY_BUFFER_ALIGNMENT,
+                });
+            }
+
+            if offset + size > buffer.size() {
+                return Err(Error::SizeMismatch {
+                    buffer: name.to_owned(),
+                    expected: buffer.size(),
+                    actual: offset + size,
+                });
+            }
+        }
+    }
+
+    Ok(ranges)
+}
+
+/// Check that every variable of `compute_pass` is bound to one of the `group_count` bind groups
+/// of its pipeline layout.
+pub(crate) fn check_bind_groups(compute_pass: &ComputePass, group_count: u32) -> Result<()> {
+    match compute_pass
+        .vars
+        .iter()
+        .find(|var| var.group >= group_count)
+    {
+        Some(var) => Err(Error::BindGroupOutOfRange {
+            shader: compute_pass.shader_type_path.clone(),
+            name: var.name.clone(),
+            group: var.group,
+            group_count,
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Check that `bytes` can be set as the push constants of `compute_pass`.
+pub(crate) fn check_push_constants(compute_pass: &ComputePass, bytes: &[u8]) -> Result<()> {
+    let size = bytes.len() as u32;
+    if !size.is_multiple_of(wgpu::PUSH_CONSTANT_ALIGNMENT)
+        || size > compute_pass.push_constants_size
+    {
+        return Err(Error::InvalidPushConstants {
+            shader: compute_pass.shader_type_path.clone(),
+            size,
+            max_size: compute_pass.push_constants_size,
+        });
+    }
+
+    Ok(())
 }
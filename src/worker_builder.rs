@@ -1,22 +1,37 @@
-use std::{borrow::Cow, marker::PhantomData, time::Duration};
+use std::{borrow::Cow, marker::PhantomData, sync::Arc, time::Duration};
 
 use bevy::{
     prelude::{AssetServer, World},
     render::{
         render_resource::{
-            encase::{private::WriteInto, StorageBuffer, UniformBuffer},
-            Buffer, ComputePipelineDescriptor, ShaderRef, ShaderType,
+            encase::{private::WriteInto, ShaderSize, StorageBuffer, UniformBuffer},
+            Buffer, ComputePipelineDescriptor, Sampler, ShaderDefVal, ShaderRef, ShaderType,
+            Texture,
         },
-        renderer::RenderDevice,
+        renderer::{RenderDevice, RenderQueue},
     },
     utils::HashMap,
 };
-use wgpu::{util::BufferInitDescriptor, BufferDescriptor, BufferUsages};
+use bytemuck::{bytes_of, pod_read_unaligned, AnyBitPattern, NoUninit};
+use wgpu::{
+    util::{BufferInitDescriptor, DispatchIndirectArgs, TextureDataOrder},
+    BufferDescriptor, BufferUsages, Extent3d, Features, SamplerDescriptor, ShaderStages,
+    StorageTextureAccess, TextureDescriptor, TextureDimension, TextureFormat,
+    TextureFormatFeatureFlags, TextureUsages, TextureViewDescriptor, TextureViewDimension,
+    COPY_BUFFER_ALIGNMENT,
+};
 
 use crate::{
+    error::{Error, Result},
+    handle::{BufferHandle, RwStorage, Staging, Storage, Uniform},
+    layout::RustLayout,
     pipeline_cache::{AppPipelineCache, CachedAppComputePipelineId},
     traits::{ComputeShader, ComputeWorker},
-    worker::{AppComputeWorker, ComputePass, RunMode, StagingBuffer, Step},
+    worker::{
+        check_bind_groups, check_push_constants, copy_ranges, AppComputeWorker, BufferKind,
+        ComputePass, ComputeTexture, ConvergeCondition, CustomStep, Dispatch, PassVar, RunMode,
+        StagingBuffer, Step, StepContext,
+    },
 };
 
 /// A builder struct to build [`AppComputeWorker<W>`]
@@ -25,8 +40,14 @@ pub struct AppComputeWorkerBuilder<'a, W: ComputeWorker> {
     pub(crate) world: &'a mut World,
     pub(crate) cached_pipeline_ids: HashMap<String, CachedAppComputePipelineId>,
     pub(crate) buffers: HashMap<String, Buffer>,
+    pub(crate) buffer_kinds: HashMap<String, BufferKind>,
+    pub(crate) buffer_layouts: HashMap<String, RustLayout>,
+    pub(crate) textures: HashMap<String, ComputeTexture>,
+    pub(crate) samplers: HashMap<String, Sampler>,
     pub(crate) staging_buffers: HashMap<String, StagingBuffer>,
     pub(crate) steps: Vec<Step>,
+    /// Index in `steps` of the steps named with `label()`.
+    pub(crate) step_labels: HashMap<String, usize>,
     pub(crate) run_mode: RunMode,
     /// Maximum duration the compute shader will run asyncronously before being set to synchronous.
     ///
@@ -34,6 +55,11 @@ pub struct AppComputeWorkerBuilder<'a, W: ComputeWorker> {
     ///
     /// 0 seconds means the shader will immediately be polled synchronously. None emeans the shader will only run asynchronously.
     pub(crate) maximum_async_time: Option<Duration>,
+    pub(crate) grow_on_write: bool,
+    /// Textures that couldn't be created with their format, reported by `try_build`.
+    unsupported_textures: Vec<(String, TextureFormat, String)>,
+    /// Passes that can't run on this render device or without reflection, reported by `try_build`.
+    pass_errors: Vec<Error>,
     extra_buffer_usages: Option<BufferUsages>,
     _phantom: PhantomData<W>,
 }
@@ -47,10 +73,18 @@ impl<'a, W: ComputeWorker> AppComputeWorkerBuilder<'a, W> {
             world,
             cached_pipeline_ids: HashMap::default(),
             buffers: HashMap::default(),
+            buffer_kinds: HashMap::default(),
+            buffer_layouts: HashMap::default(),
+            textures: HashMap::default(),
+            samplers: HashMap::default(),
             staging_buffers: HashMap::default(),
             steps: vec![],
+            step_labels: HashMap::default(),
             run_mode: RunMode::Continuous,
             maximum_async_time: Some(Duration::from_secs(0)),
+            grow_on_write: false,
+            unsupported_textures: vec![],
+            pass_errors: vec![],
             extra_buffer_usages: None,
             _phantom: PhantomData,
         }
@@ -64,11 +98,15 @@ impl<'a, W: ComputeWorker> AppComputeWorkerBuilder<'a, W> {
 
         let render_device = self.world.resource::<RenderDevice>();
 
-        let mut usage = BufferUsages::COPY_DST | BufferUsages::UNIFORM;
+        let mut usage = BufferUsages::COPY_DST | BufferUsages::COPY_SRC | BufferUsages::UNIFORM;
         if let Some(extra_usages) = self.extra_buffer_usages {
             usage |= extra_usages;
         }
 
+        self.buffer_kinds
+            .insert(name.to_owned(), BufferKind::Uniform);
+        self.buffer_layouts
+            .insert(name.to_owned(), RustLayout::of(uniform));
         self.buffers.insert(
             name.to_owned(),
             render_device.create_buffer_with_data(&BufferInitDescriptor {
@@ -81,17 +119,25 @@ impl<'a, W: ComputeWorker> AppComputeWorkerBuilder<'a, W> {
     }
 
     /// Add a new storage buffer to the worker, and fill it with `storage`. It will be read only.
-    pub fn add_storage<T: ShaderType + WriteInto>(&mut self, name: &str, storage: &T) -> &mut Self {
+    pub fn add_storage<T: ?Sized + ShaderType + WriteInto>(
+        &mut self,
+        name: &str,
+        storage: &T,
+    ) -> &mut Self {
         let mut buffer = StorageBuffer::new(Vec::new());
         buffer.write::<T>(storage).unwrap();
 
         let render_device = self.world.resource::<RenderDevice>();
 
-        let mut usage = BufferUsages::COPY_DST | BufferUsages::STORAGE;
+        let mut usage = BufferUsages::COPY_DST | BufferUsages::COPY_SRC | BufferUsages::STORAGE;
         if let Some(extra_usages) = self.extra_buffer_usages {
             usage |= extra_usages;
         }
 
+        self.buffer_kinds
+            .insert(name.to_owned(), BufferKind::Storage);
+        self.buffer_layouts
+            .insert(name.to_owned(), RustLayout::of(storage));
         self.buffers.insert(
             name.to_owned(),
             render_device.create_buffer_with_data(&BufferInitDescriptor {
@@ -104,7 +150,7 @@ impl<'a, W: ComputeWorker> AppComputeWorkerBuilder<'a, W> {
     }
 
     /// Add a new read/write storage buffer to the worker, and fill it with `storage`.
-    pub fn add_rw_storage<T: ShaderType + WriteInto>(
+    pub fn add_rw_storage<T: ?Sized + ShaderType + WriteInto>(
         &mut self,
         name: &str,
         storage: &T,
@@ -114,11 +160,18 @@ impl<'a, W: ComputeWorker> AppComputeWorkerBuilder<'a, W> {
 
         let render_device = self.world.resource::<RenderDevice>();
 
-        let mut usage = BufferUsages::COPY_DST | BufferUsages::COPY_SRC | BufferUsages::STORAGE;
+        let mut usage = BufferUsages::COPY_DST
+            | BufferUsages::COPY_SRC
+            | BufferUsages::STORAGE
+            | BufferUsages::INDIRECT;
         if let Some(extra_usages) = self.extra_buffer_usages {
             usage |= extra_usages;
         }
 
+        self.buffer_kinds
+            .insert(name.to_owned(), BufferKind::RwStorage);
+        self.buffer_layouts
+            .insert(name.to_owned(), RustLayout::of(storage));
         self.buffers.insert(
             name.to_owned(),
             render_device.create_buffer_with_data(&BufferInitDescriptor {
@@ -134,36 +187,74 @@ impl<'a, W: ComputeWorker> AppComputeWorkerBuilder<'a, W> {
     /// Additionally, it will create a read/write storage buffer to access from
     /// your shaders.
     /// The buffer will be filled with `data`
-    pub fn add_staging<T: ShaderType + WriteInto>(&mut self, name: &str, data: &T) -> &mut Self {
+    pub fn add_staging<T: ?Sized + ShaderType + WriteInto>(
+        &mut self,
+        name: &str,
+        data: &T,
+    ) -> &mut Self {
         self.add_rw_storage(name, data);
         let buffer = self.buffers.get(name).unwrap();
 
         let render_device = self.world.resource::<RenderDevice>();
 
-        let staging = StagingBuffer {
-            mapped: true,
-            buffer: render_device.create_buffer(&BufferDescriptor {
-                label: Some(name),
-                size: buffer.size(),
-                usage: BufferUsages::MAP_READ | BufferUsages::COPY_DST,
-                mapped_at_creation: true,
-            }),
-        };
+        let staging = StagingBuffer::new(render_device, name, buffer.size());
 
         self.staging_buffers.insert(name.to_owned(), staging);
 
         self
     }
 
+    /// Same as [`Self::add_uniform`], but returns a [`BufferHandle`] to access the buffer.
+    pub fn add_uniform_handle<T: ShaderType + WriteInto>(
+        &mut self,
+        name: &str,
+        uniform: &T,
+    ) -> BufferHandle<T, Uniform> {
+        self.add_uniform(name, uniform);
+        BufferHandle::new(name)
+    }
+
+    /// Same as [`Self::add_storage`], but returns a [`BufferHandle`] to access the buffer.
+    pub fn add_storage_handle<T: ShaderSize + WriteInto>(
+        &mut self,
+        name: &str,
+        storage: &[T],
+    ) -> BufferHandle<T, Storage> {
+        self.add_storage(name, storage);
+        BufferHandle::new(name)
+    }
+
+    /// Same as [`Self::add_rw_storage`], but returns a [`BufferHandle`] to access the buffer.
+    pub fn add_rw_storage_handle<T: ShaderSize + WriteInto>(
+        &mut self,
+        name: &str,
+        storage: &[T],
+    ) -> BufferHandle<T, RwStorage> {
+        self.add_rw_storage(name, storage);
+        BufferHandle::new(name)
+    }
+
+    /// Same as [`Self::add_staging`], but returns a [`BufferHandle`] to access the buffer.
+    pub fn add_staging_handle<T: ShaderSize + WriteInto>(
+        &mut self,
+        name: &str,
+        data: &[T],
+    ) -> BufferHandle<T, Staging> {
+        self.add_staging(name, data);
+        BufferHandle::new(name)
+    }
+
     /// Add a new empty uniform buffer to the worker.
     pub fn add_empty_uniform(&mut self, name: &str, size: u64) -> &mut Self {
         let render_device = self.world.resource::<RenderDevice>();
 
-        let mut usage = BufferUsages::COPY_DST | BufferUsages::UNIFORM;
+        let mut usage = BufferUsages::COPY_DST | BufferUsages::COPY_SRC | BufferUsages::UNIFORM;
         if let Some(extra_usages) = self.extra_buffer_usages {
             usage |= extra_usages;
         }
 
+        self.buffer_kinds
+            .insert(name.to_owned(), BufferKind::Uniform);
         self.buffers.insert(
             name.to_owned(),
             render_device.create_buffer(&BufferDescriptor {
@@ -181,11 +272,13 @@ impl<'a, W: ComputeWorker> AppComputeWorkerBuilder<'a, W> {
     pub fn add_empty_storage(&mut self, name: &str, size: u64) -> &mut Self {
         let render_device = self.world.resource::<RenderDevice>();
 
-        let mut usage = BufferUsages::COPY_DST | BufferUsages::STORAGE;
+        let mut usage = BufferUsages::COPY_DST | BufferUsages::COPY_SRC | BufferUsages::STORAGE;
         if let Some(extra_usages) = self.extra_buffer_usages {
             usage |= extra_usages;
         }
 
+        self.buffer_kinds
+            .insert(name.to_owned(), BufferKind::Storage);
         self.buffers.insert(
             name.to_owned(),
             render_device.create_buffer(&BufferDescriptor {
@@ -202,11 +295,16 @@ impl<'a, W: ComputeWorker> AppComputeWorkerBuilder<'a, W> {
     pub fn add_empty_rw_storage(&mut self, name: &str, size: u64) -> &mut Self {
         let render_device = self.world.resource::<RenderDevice>();
 
-        let mut usage = BufferUsages::COPY_DST | BufferUsages::COPY_SRC | BufferUsages::STORAGE;
+        let mut usage = BufferUsages::COPY_DST
+            | BufferUsages::COPY_SRC
+            | BufferUsages::STORAGE
+            | BufferUsages::INDIRECT;
         if let Some(extra_usages) = self.extra_buffer_usages {
             usage |= extra_usages;
         }
 
+        self.buffer_kinds
+            .insert(name.to_owned(), BufferKind::RwStorage);
         self.buffers.insert(
             name.to_owned(),
             render_device.create_buffer(&BufferDescriptor {
@@ -230,62 +328,493 @@ impl<'a, W: ComputeWorker> AppComputeWorkerBuilder<'a, W> {
 
         let render_device = self.world.resource::<RenderDevice>();
 
-        let staging = StagingBuffer {
-            mapped: true,
-            buffer: render_device.create_buffer(&BufferDescriptor {
+        let staging = StagingBuffer::new(render_device, name, buffer.size());
+
+        self.staging_buffers.insert(name.to_owned(), staging);
+
+        self
+    }
+
+    /// Add a new storage texture to the worker.
+    ///
+    /// `dimension` is the view dimension it will be bound as (`D2`, `D2Array` or `D3`),
+    /// `access` is the access mode declared in your shader,
+    /// eg: `texture_storage_2d<rgba32float, write>` is `StorageTextureAccess::WriteOnly`.
+    ///
+    /// `try_build` returns an error if `format` can't be used for such a storage texture.
+    pub fn add_storage_texture(
+        &mut self,
+        name: &str,
+        dimension: TextureViewDimension,
+        size: Extent3d,
+        format: TextureFormat,
+        access: StorageTextureAccess,
+    ) -> &mut Self {
+        let render_device = self.world.resource::<RenderDevice>();
+
+        let format_features = format.guaranteed_format_features(render_device.features());
+        if !format_features
+            .allowed_usages
+            .contains(TextureUsages::STORAGE_BINDING)
+            || (access == StorageTextureAccess::ReadWrite
+                && !format_features
+                    .flags
+                    .contains(TextureFormatFeatureFlags::STORAGE_READ_WRITE))
+        {
+            self.unsupported_textures.push((
+                name.to_owned(),
+                format,
+                format!("as a {access:?} storage texture"),
+            ));
+            return self;
+        }
+
+        let texture = render_device.create_texture(&TextureDescriptor {
+            label: Some(name),
+            size,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: texture_dimension(dimension),
+            format,
+            usage: TextureUsages::STORAGE_BINDING
+                | TextureUsages::TEXTURE_BINDING
+                | TextureUsages::COPY_SRC
+                | TextureUsages::COPY_DST,
+            view_formats: &[],
+        });
+
+        self.insert_texture(name, texture, dimension, Some(access));
+        self
+    }
+
+    /// Add a new sampled texture to the worker, and fill it with `data`.
+    ///
+    /// `data` is the raw texel data, tightly packed, layer by layer.
+    pub fn add_texture(
+        &mut self,
+        name: &str,
+        dimension: TextureViewDimension,
+        size: Extent3d,
+        format: TextureFormat,
+        data: &[u8],
+    ) -> &mut Self {
+        let render_device = self.world.resource::<RenderDevice>();
+        let render_queue = self.world.resource::<RenderQueue>();
+
+        let texture = render_device.create_texture_with_data(
+            render_queue,
+            &TextureDescriptor {
                 label: Some(name),
-                size: buffer.size(),
-                usage: BufferUsages::MAP_READ | BufferUsages::COPY_DST,
-                mapped_at_creation: true,
-            }),
+                size,
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: texture_dimension(dimension),
+                format,
+                usage: TextureUsages::TEXTURE_BINDING
+                    | TextureUsages::COPY_SRC
+                    | TextureUsages::COPY_DST,
+                view_formats: &[],
+            },
+            TextureDataOrder::LayerMajor,
+            data,
+        );
+
+        self.insert_texture(name, texture, dimension, None);
+        self
+    }
+
+    /// Add a new storage texture to the worker, along with a staging buffer
+    /// so its texels can be read back with `read_texture_vec()`.
+    pub fn add_staging_texture(
+        &mut self,
+        name: &str,
+        dimension: TextureViewDimension,
+        size: Extent3d,
+        format: TextureFormat,
+        access: StorageTextureAccess,
+    ) -> &mut Self {
+        self.add_storage_texture(name, dimension, size, format, access);
+        let Some(texture) = self.textures.get(name) else {
+            return self;
+        };
+
+        let Some(staging_size) = texture.staging_size() else {
+            self.unsupported_textures.push((
+                name.to_owned(),
+                format,
+                String::from("in a copy to a staging buffer"),
+            ));
+            return self;
         };
 
+        let render_device = self.world.resource::<RenderDevice>();
+
+        let staging = StagingBuffer::new(render_device, name, staging_size);
+
         self.staging_buffers.insert(name.to_owned(), staging);
 
         self
     }
 
+    /// Add a new sampler to the worker.
+    pub fn add_sampler(&mut self, name: &str, descriptor: &SamplerDescriptor) -> &mut Self {
+        let render_device = self.world.resource::<RenderDevice>();
+
+        self.samplers
+            .insert(name.to_owned(), render_device.create_sampler(descriptor));
+        self
+    }
+
+    fn insert_texture(
+        &mut self,
+        name: &str,
+        texture: Texture,
+        dimension: TextureViewDimension,
+        access: Option<StorageTextureAccess>,
+    ) {
+        let view = texture.create_view(&TextureViewDescriptor {
+            label: Some(name),
+            dimension: Some(dimension),
+            ..Default::default()
+        });
+
+        self.textures.insert(
+            name.to_owned(),
+            ComputeTexture {
+                texture,
+                view,
+                access,
+            },
+        );
+    }
+
     /// Add a new compute pass to your worker.
     /// They will run sequentially in the order you insert them.
+    ///
+    /// `vars` are bound in order to `@group(0)`, the first one at `@binding(0)`.
     pub fn add_pass<S: ComputeShader>(&mut self, workgroups: [u32; 3], vars: &[&str]) -> &mut Self {
-        if !self.cached_pipeline_ids.contains_key(S::type_path()) {
-            let pipeline_cache = self.world.resource::<AppPipelineCache>();
-
-            let asset_server = self.world.resource::<AssetServer>();
-            let shader = match S::shader() {
-                ShaderRef::Default => None,
-                ShaderRef::Handle(handle) => Some(handle),
-                ShaderRef::Path(path) => Some(asset_server.load(path)),
-            }
-            .unwrap();
-
-            let cached_id = pipeline_cache.queue_app_compute_pipeline(ComputePipelineDescriptor {
-                label: None,
-                layout: S::layouts().to_vec(),
-                push_constant_ranges: S::push_constant_ranges().to_vec(),
-                shader_defs: S::shader_defs().to_vec(),
-                entry_point: Cow::Borrowed(S::entry_point()),
-                shader,
-            });
+        let vars = vars
+            .iter()
+            .enumerate()
+            .map(|(binding, name)| PassVar {
+                group: 0,
+                binding: binding as u32,
+                name: String::from(*name),
+            })
+            .collect();
+
+        self.push_pass::<S>(workgroups, vars)
+    }
+
+    /// Add a new compute pass to your worker, placing each variable at an explicit
+    /// `(group, binding, name)`.
+    ///
+    /// Useful for shaders that leave gaps in their binding numbers, or that use several bind
+    /// groups, eg: through an imported module declaring its own `@group(1)` variables.
+    pub fn add_pass_with_bindings<S: ComputeShader>(
+        &mut self,
+        workgroups: [u32; 3],
+        vars: &[(u32, u32, &str)],
+    ) -> &mut Self {
+        let vars = vars
+            .iter()
+            .map(|(group, binding, name)| PassVar {
+                group: *group,
+                binding: *binding,
+                name: String::from(*name),
+            })
+            .collect();
+
+        self.push_pass::<S>(workgroups, vars)
+    }
+
+    /// Same as [`Self::add_pass`], also setting the push constants of the pass to `push_constants`.
+    ///
+    /// The shader must declare a push constant range for the compute stage in
+    /// [`ComputeShader::push_constant_ranges`]. Update them at run time with
+    /// [`AppComputeWorker::set_push_constants`].
+    pub fn add_pass_with_push_constants<S: ComputeShader>(
+        &mut self,
+        workgroups: [u32; 3],
+        vars: &[&str],
+        push_constants: &[u8],
+    ) -> &mut Self {
+        self.add_pass::<S>(workgroups, vars);
+
+        if let Some(Step::ComputePass(compute_pass)) = self.steps.last_mut() {
+            compute_pass.push_constants = push_constants.to_vec();
+        }
+        self
+    }
+
+    /// Add a new compute pass to your worker, binding every global variable of the shader to the
+    /// buffer, texture or sampler with the same name.
+    ///
+    /// Bindings are resolved from the shader once its pipeline is ready. If a global used by the
+    /// entry point has no matching name, a worker created with [`Self::build`] panics, and one
+    /// created with [`Self::try_build`] stops running and returns [`Error::UnmatchedGlobals`] from
+    /// [`AppComputeWorker::validation`].
+    pub fn add_pass_auto<S: ComputeShader>(&mut self, workgroups: [u32; 3]) -> &mut Self {
+        self.push_pass::<S>(workgroups, vec![]);
+
+        if let Some(Step::ComputePass(compute_pass)) = self.steps.last_mut() {
+            compute_pass.reflect_vars = true;
+        }
+        self
+    }
+
+    /// Add a new compute pass to your worker, dispatching enough workgroups to cover `elements`
+    /// invocations in each dimension.
+    ///
+    /// The workgroup size is read from the `@workgroup_size` of the entry point once its pipeline is
+    /// ready, or from [`ComputeShader::workgroup_size`] for shaders that can't be reflected, like
+    /// SPIR-V shaders.
+    pub fn add_pass_for_elements<S: ComputeShader>(
+        &mut self,
+        elements: [u32; 3],
+        vars: &[&str],
+    ) -> &mut Self {
+        let spirv = matches!(S::shader(), ShaderRef::Path(path)
+            if path.path().extension().is_some_and(|extension| extension == "spv"));
+        if spirv && S::workgroup_size().is_none() {
+            self.pass_errors
+                .push(Error::ReflectionUnavailable(S::type_path().to_owned()));
+        }
+
+        self.add_pass::<S>(elements, vars);
+
+        if let Some(Step::ComputePass(compute_pass)) = self.steps.last_mut() {
+            compute_pass.dispatch = Dispatch::Elements(elements);
+        }
+        self
+    }
+
+    /// Add a new compute pass to your worker, dispatching the number of workgroups stored as three
+    /// `u32` at `offset` in `args_buffer`.
+    ///
+    /// A previous pass can compute how much work exists and write it to `args_buffer`, which must
+    /// be created with [`Self::add_rw_storage`] or [`Self::add_staging`], without any readback.
+    pub fn add_indirect_pass<S: ComputeShader>(
+        &mut self,
+        args_buffer: &str,
+        offset: u64,
+        vars: &[&str],
+    ) -> &mut Self {
+        self.add_pass::<S>([0; 3], vars);
 
-            self.cached_pipeline_ids
-                .insert(S::type_path().to_string(), cached_id);
+        if let Some(Step::ComputePass(compute_pass)) = self.steps.last_mut() {
+            compute_pass.dispatch = Dispatch::Indirect {
+                buffer: args_buffer.to_owned(),
+                offset,
+            };
         }
+        self
+    }
+
+    fn push_pass<S: ComputeShader>(
+        &mut self,
+        workgroups: [u32; 3],
+        vars: Vec<PassVar>,
+    ) -> &mut Self {
+        self.queue_pipeline::<S>();
+
+        let push_constants_size = S::push_constant_ranges()
+            .iter()
+            .filter(|range| range.stages.contains(ShaderStages::COMPUTE))
+            .map(|range| range.range.end)
+            .max()
+            .unwrap_or(0);
 
         self.steps.push(Step::ComputePass(ComputePass {
-            workgroups,
-            vars: vars.iter().map(|a| String::from(*a)).collect(),
+            dispatch: Dispatch::Workgroups(workgroups),
+            vars,
+            reflect_vars: false,
             shader_type_path: S::type_path().to_string(),
+            push_constants: vec![],
+            push_constants_size,
+            layout_count: S::layouts().len() as u32,
+            workgroup_size: S::workgroup_size(),
         }));
         self
     }
 
+    fn queue_pipeline<S: ComputeShader>(&mut self) {
+        if self.cached_pipeline_ids.contains_key(S::type_path()) {
+            return;
+        }
+
+        // wgpu would fail to create the pipeline, so report it from `try_build` instead
+        let features = self.world.resource::<RenderDevice>().features();
+        if !S::push_constant_ranges().is_empty() && !features.contains(Features::PUSH_CONSTANTS) {
+            self.pass_errors.push(Error::MissingFeature {
+                shader: S::type_path().to_owned(),
+                feature: Features::PUSH_CONSTANTS,
+            });
+            return;
+        }
+
+        let pipeline_cache = self.world.resource::<AppPipelineCache>();
+
+        let asset_server = self.world.resource::<AssetServer>();
+        let shader = match S::shader() {
+            ShaderRef::Default => None,
+            ShaderRef::Handle(handle) => Some(handle),
+            ShaderRef::Path(path) => Some(asset_server.load(path)),
+        }
+        .unwrap();
+
+        let mut shader_defs = S::shader_defs().to_vec();
+        if let Some([x, y, z]) = S::workgroup_size() {
+            shader_defs.extend([
+                ShaderDefVal::UInt("WORKGROUP_SIZE_X".into(), x),
+                ShaderDefVal::UInt("WORKGROUP_SIZE_Y".into(), y),
+                ShaderDefVal::UInt("WORKGROUP_SIZE_Z".into(), z),
+            ]);
+        }
+
+        let cached_id = pipeline_cache.queue_app_compute_pipeline(ComputePipelineDescriptor {
+            label: None,
+            layout: S::layouts().to_vec(),
+            push_constant_ranges: S::push_constant_ranges().to_vec(),
+            shader_defs,
+            entry_point: Cow::Borrowed(S::entry_point()),
+            shader,
+        });
+
+        self.cached_pipeline_ids
+            .insert(S::type_path().to_string(), cached_id);
+    }
+
     pub fn add_swap(&mut self, buffer_a: &str, buffer_b: &str) -> &mut Self {
         self.steps
             .push(Step::Swap(buffer_a.to_owned(), buffer_b.to_owned()));
         self
     }
 
+    /// Copy `(src_offset, dst_offset, size)` byte ranges from buffer `src` to buffer `dst`,
+    /// or all of `src` if `ranges` is empty, e.g. to keep a snapshot of a buffer between passes.
+    /// `src` and `dst` must be different buffers.
+    pub fn add_copy(&mut self, src: &str, dst: &str, ranges: &[(u64, u64, u64)]) -> &mut Self {
+        self.steps.push(Step::Copy {
+            src: src.to_owned(),
+            dst: dst.to_owned(),
+            ranges: ranges.to_vec(),
+        });
+        self
+    }
+
+    /// Set every byte of buffer `name` to zero, e.g. to reset an accumulator before a pass.
+    pub fn add_clear(&mut self, name: &str) -> &mut Self {
+        self.steps.push(Step::Clear(name.to_owned()));
+        self
+    }
+
+    /// Repeat `value` over the whole buffer `name`.
+    pub fn add_fill<T: NoUninit>(&mut self, name: &str, value: T) -> &mut Self {
+        self.steps.push(Step::Fill {
+            buffer: name.to_owned(),
+            value: bytes_of(&value).to_vec(),
+            source: None,
+        });
+        self
+    }
+
+    /// Record commands that the other steps can't express, such as timestamp writes or copies to a
+    /// texture owned elsewhere. `step` is called on every run, in order with the other steps.
+    pub fn add_custom_step(
+        &mut self,
+        step: impl Fn(&mut StepContext) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.steps.push(Step::Custom(CustomStep(Arc::new(step))));
+        self
+    }
+
+    /// Name the last step added, so it can be targeted at run time by `label` instead of its index,
+    /// e.g. with [`AppComputeWorker::set_workgroups`].
+    ///
+    /// After [`Self::add_repeat`], this names the whole repeated group.
+    pub fn label(&mut self, label: &str) -> &mut Self {
+        // Skip over the steps of finished groups, those being built have no length yet
+        let mut last = None;
+        let mut i = 0;
+        while i < self.steps.len() {
+            last = Some(i);
+            i += 1 + self.steps[i].group_len();
+        }
+
+        let index = last.expect("`label()` must be called after adding the step it names");
+        self.step_labels.insert(label.to_owned(), index);
+        self
+    }
+
+    /// Run the steps added by `steps` `count` times, e.g. a pass and a swap for each iteration of a
+    /// solver. All iterations are encoded in the same command encoder.
+    ///
+    /// Update `count` at run time with [`AppComputeWorker::set_repeat_count`].
+    pub fn add_repeat(
+        &mut self,
+        count: u32,
+        steps: impl FnOnce(&mut Self) -> &mut Self,
+    ) -> &mut Self {
+        let index = self.steps.len();
+        self.steps.push(Step::Repeat { count, len: 0 });
+
+        steps(self);
+
+        let len = self.steps.len() - index - 1;
+        self.steps[index] = Step::Repeat { count, len };
+        self
+    }
+
+    /// Run the steps added by `steps` until `condition` holds for the value of type `T` at the start
+    /// of the `flag` buffer, or `max_iterations` times, e.g. a flood fill until no cell changed.
+    ///
+    /// Only `flag` is read back after each iteration, the steps following the group run once it
+    /// converged. The number of iterations it took is given by [`AppComputeWorker::iterations`].
+    /// Converging groups cannot be nested in other groups.
+    pub fn add_converge<T: AnyBitPattern>(
+        &mut self,
+        flag: &str,
+        max_iterations: u32,
+        condition: impl Fn(&T) -> bool + Send + Sync + 'static,
+        steps: impl FnOnce(&mut Self) -> &mut Self,
+    ) -> &mut Self {
+        let size = (std::mem::size_of::<T>() as u64).next_multiple_of(COPY_BUFFER_ALIGNMENT);
+        let staging = self
+            .world
+            .resource::<RenderDevice>()
+            .create_buffer(&BufferDescriptor {
+                label: Some(flag),
+                size,
+                usage: BufferUsages::MAP_READ | BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            });
+        let condition = ConvergeCondition(Arc::new(move |bytes: &[u8]| {
+            condition(&pod_read_unaligned(&bytes[..std::mem::size_of::<T>()]))
+        }));
+
+        let index = self.steps.len();
+        self.steps.push(Step::Converge {
+            flag: flag.to_owned(),
+            staging: staging.clone(),
+            condition: condition.clone(),
+            max_iterations,
+            len: 0,
+        });
+
+        steps(self);
+
+        let len = self.steps.len() - index - 1;
+        self.steps[index] = Step::Converge {
+            flag: flag.to_owned(),
+            staging,
+            condition,
+            max_iterations,
+            len,
+        };
+        self
+    }
+
     /// Setting this will make all subsequent buffer creations append the provided usages.
     /// Eg: `set_extra_buffer_usages(usages: Some(BufferUsages::VERTEX))`
     /// Unset with: `set_extra_buffer_usages(usages: None)`
@@ -307,6 +836,27 @@ impl<'a, W: ComputeWorker> AppComputeWorkerBuilder<'a, W> {
         self
     }
 
+    /// The worker will run once every `frames` frames, starting `frames` frames after it is ready.
+    /// If it is still working when a run is due, it runs as soon as it is done.
+    pub fn every_n_frames(&mut self, frames: u32) -> &mut Self {
+        self.run_mode = RunMode::EveryNFrames(frames);
+        self
+    }
+
+    /// The worker will run once every `period` of the bevy [`Time`](bevy::prelude::Time).
+    /// If it is still working when a run is due, it runs as soon as it is done.
+    pub fn every(&mut self, period: Duration) -> &mut Self {
+        self.run_mode = RunMode::Every(period);
+        self
+    }
+
+    /// The worker will run once with the initial contents of its buffers, then only on the frames
+    /// following writes or resizes of its buffers.
+    pub fn on_input_change(&mut self) -> &mut Self {
+        self.run_mode = RunMode::OnInputChange;
+        self
+    }
+
     /// The worker will block the frame it is run on until it compltes. This is the default behavior
     pub fn synchronous(&mut self) -> &mut Self {
         self.maximum_async_time = Some(Duration::from_secs(0));
@@ -329,8 +879,159 @@ impl<'a, W: ComputeWorker> AppComputeWorkerBuilder<'a, W> {
         self
     }
 
+    /// Writing more data than a buffer can hold will reallocate it, with its staging buffer,
+    /// instead of failing. See [`AppComputeWorker::resize_buffer`].
+    pub fn grow_on_write(&mut self) -> &mut Self {
+        self.grow_on_write = true;
+        self
+    }
+
     /// Build an [`AppComputeWorker<W>`] from this builder.
+    ///
+    /// Panics if a step uses a variable that was never added to the builder, and once its
+    /// pipelines are ready if a pass doesn't match its shader.
     pub fn build(&self) -> AppComputeWorker<W> {
-        AppComputeWorker::from(self)
+        match self.try_build() {
+            Ok(mut worker) => {
+                worker.panic_on_invalid = true;
+                worker
+            }
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Build an [`AppComputeWorker<W>`] from this builder.
+    ///
+    /// Returns an error if a step uses a variable that was never added to the builder.
+    /// The passes are checked against their shaders once their pipelines are ready, see
+    /// [`AppComputeWorker::validation`].
+    pub fn try_build(&self) -> Result<AppComputeWorker<W>> {
+        if let Some(err) = self.pass_errors.first() {
+            return Err(err.clone());
+        }
+
+        if let Some((texture, format, usage)) = self.unsupported_textures.first() {
+            return Err(Error::UnsupportedTextureFormat {
+                texture: texture.clone(),
+                format: *format,
+                usage: usage.clone(),
+            });
+        }
+
+        for (i, step) in self.steps.iter().enumerate() {
+            let group = &self.steps[i + 1..i + 1 + step.group_len()];
+            if group
+                .iter()
+                .any(|step| matches!(step, Step::Converge { .. }))
+            {
+                return Err(Error::InvalidStep(format!("{step:?}")));
+            }
+
+            match step {
+                Step::ComputePass(compute_pass) => {
+                    check_push_constants(compute_pass, &compute_pass.push_constants)?;
+
+                    if compute_pass.layout_count > 0 {
+                        check_bind_groups(compute_pass, compute_pass.layout_count)?;
+                    }
+
+                    if let Dispatch::Indirect { buffer, offset } = &compute_pass.dispatch {
+                        self.check_indirect_buffer(buffer, *offset)?;
+                    }
+
+                    for var in &compute_pass.vars {
+                        if !self.buffers.contains_key(&var.name)
+                            && !self.textures.contains_key(&var.name)
+                            && !self.samplers.contains_key(&var.name)
+                        {
+                            return Err(Error::BufferNotFound(var.name.clone()));
+                        }
+                    }
+                }
+                Step::Swap(buffer_a, buffer_b) => {
+                    for name in [buffer_a, buffer_b] {
+                        if !self.buffers.contains_key(name) && !self.textures.contains_key(name) {
+                            return Err(Error::BufferNotFound(name.clone()));
+                        }
+                    }
+                }
+                Step::Copy { src, dst, ranges } => {
+                    let Some(src_buffer) = self.buffers.get(src) else {
+                        return Err(Error::BufferNotFound(src.clone()));
+                    };
+
+                    let Some(dst_buffer) = self.buffers.get(dst) else {
+                        return Err(Error::BufferNotFound(dst.clone()));
+                    };
+
+                    copy_ranges(src, src_buffer, dst, dst_buffer, ranges)?;
+                }
+                Step::Fill { value, .. } if value.is_empty() => {
+                    return Err(Error::InvalidStep(format!("{step:?}")));
+                }
+                Step::Clear(name) | Step::Fill { buffer: name, .. } => {
+                    if !self.buffers.contains_key(name) {
+                        return Err(Error::BufferNotFound(name.clone()));
+                    }
+                }
+                Step::Converge { flag, staging, .. } => {
+                    let Some(buffer) = self.buffers.get(flag) else {
+                        return Err(Error::BufferNotFound(flag.clone()));
+                    };
+
+                    if buffer.size() < staging.size() {
+                        return Err(Error::SizeMismatch {
+                            buffer: flag.clone(),
+                            expected: buffer.size(),
+                            actual: staging.size(),
+                        });
+                    }
+                }
+                Step::Custom(_) | Step::Repeat { .. } => {}
+            }
+        }
+
+        Ok(AppComputeWorker::from(self))
+    }
+
+    fn check_indirect_buffer(&self, name: &str, offset: u64) -> Result<()> {
+        let Some(buffer) = self.buffers.get(name) else {
+            return Err(Error::BufferNotFound(name.to_owned()));
+        };
+
+        if !buffer.usage().contains(BufferUsages::INDIRECT) {
+            return Err(Error::NotIndirect(name.to_owned()));
+        }
+
+        let size = std::mem::size_of::<DispatchIndirectArgs>() as u64;
+        if !offset.is_multiple_of(COPY_BUFFER_ALIGNMENT) {
+            return Err(Error::Misaligned {
+                buffer: name.to_owned(),
+                offset,
+                size,
+                alignment: COPY_BUFFER_ALIGNMENT,
+            });
+        }
+
+        if offset + size > buffer.size() {
+            return Err(Error::SizeMismatch {
+                buffer: name.to_owned(),
+                expected: buffer.size(),
+                actual: offset + size,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+fn texture_dimension(dimension: TextureViewDimension) -> TextureDimension {
+    match dimension {
+        TextureViewDimension::D1 => TextureDimension::D1,
+        TextureViewDimension::D3 => TextureDimension::D3,
+        TextureViewDimension::D2
+        | TextureViewDimension::D2Array
+        | TextureViewDimension::Cube
+        | TextureViewDimension::CubeArray => TextureDimension::D2,
     }
 }
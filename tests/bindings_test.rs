@@ -0,0 +1,131 @@
+use bevy::prelude::*;
+use bevy_easy_compute::prelude::*;
+
+mod common;
+
+#[derive(TypePath)]
+struct BindingsShader;
+
+impl ComputeShader for BindingsShader {
+    fn shader() -> ShaderRef {
+        "shaders/bindings.wgsl".into()
+    }
+}
+
+#[derive(Resource)]
+struct ExplicitBindingsWorker;
+
+impl ComputeWorker for ExplicitBindingsWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        AppComputeWorkerBuilder::new(world)
+            .add_uniform("uni", &2.)
+            .add_staging("values", &[1., 2., 3., 4.])
+            .add_pass_with_bindings::<BindingsShader>([4, 1, 1], &[(0, 3, "uni"), (2, 1, "values")])
+            .build()
+    }
+}
+
+#[test]
+fn binds_explicit_groups_and_bindings() {
+    fn test(compute_worker: Res<AppComputeWorker<ExplicitBindingsWorker>>) {
+        let result: Vec<f32> = compute_worker.read_vec("values");
+        assert_eq!(result, [2., 4., 6., 8.]);
+    }
+
+    let mut app = common::build_app::<ExplicitBindingsWorker>();
+    app.add_systems(Update, test);
+    app.update();
+}
+
+#[derive(Resource)]
+struct ReflectedBindingsWorker;
+
+impl ComputeWorker for ReflectedBindingsWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        AppComputeWorkerBuilder::new(world)
+            .add_uniform("uni", &3.)
+            .add_staging("values", &[1., 2., 3., 4.])
+            .add_pass_auto::<BindingsShader>([4, 1, 1])
+            .build()
+    }
+}
+
+#[test]
+fn binds_globals_by_name() {
+    fn test(compute_worker: Res<AppComputeWorker<ReflectedBindingsWorker>>) {
+        let result: Vec<f32> = compute_worker.read_vec("values");
+        assert_eq!(result, [3., 6., 9., 12.]);
+    }
+
+    let mut app = common::build_app::<ReflectedBindingsWorker>();
+    app.add_systems(Update, test);
+    app.update();
+}
+
+#[derive(Resource)]
+struct UnmatchedBindingsWorker;
+
+impl ComputeWorker for UnmatchedBindingsWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        AppComputeWorkerBuilder::new(world)
+            .add_staging("values", &[1., 2., 3., 4.])
+            .add_pass_auto::<BindingsShader>([4, 1, 1])
+            .build()
+    }
+}
+
+#[test]
+#[should_panic(expected = "uni (@group(0) @binding(3))")]
+fn reports_unmatched_globals() {
+    common::build_app::<UnmatchedBindingsWorker>();
+}
+
+#[derive(Resource)]
+struct OutOfRangeGroupWorker;
+
+impl ComputeWorker for OutOfRangeGroupWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        AppComputeWorkerBuilder::new(world)
+            .add_uniform("uni", &2.)
+            .add_staging("values", &[1., 2., 3., 4.])
+            .add_pass_with_bindings::<BindingsShader>([4, 1, 1], &[(0, 3, "uni"), (5, 1, "values")])
+            .build()
+    }
+}
+
+#[test]
+#[should_panic(expected = "has 3 bind groups, values can't be bound to @group(5)")]
+fn reports_groups_out_of_range() {
+    common::build_app::<OutOfRangeGroupWorker>();
+}
+
+#[derive(Resource)]
+struct FallibleBindingsWorker;
+
+impl ComputeWorker for FallibleBindingsWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        AppComputeWorkerBuilder::new(world)
+            .add_staging("values", &[1., 2., 3., 4.])
+            .add_pass_auto::<BindingsShader>([4, 1, 1])
+            .try_build()
+            .unwrap()
+    }
+}
+
+#[test]
+fn returns_unmatched_globals_of_fallible_workers() {
+    let mut app = common::build_app_with::<ExplicitBindingsWorker>(|app| {
+        app.add_plugins(AppComputeWorkerPlugin::<FallibleBindingsWorker>::default());
+    });
+    app.update();
+
+    let compute_worker = app
+        .world()
+        .resource::<AppComputeWorker<FallibleBindingsWorker>>();
+    assert!(matches!(
+        compute_worker.validation(),
+        Err(bevy_easy_compute::Error::UnmatchedGlobals { globals, .. }) if globals.len() == 1
+    ));
+    assert!(!compute_worker.ready());
+    assert_eq!(compute_worker.runs(), 0);
+}
@@ -0,0 +1,199 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_easy_compute::{prelude::*, Error};
+
+mod common;
+
+#[derive(TypePath)]
+struct SimpleShader;
+
+impl ComputeShader for SimpleShader {
+    fn shader() -> ShaderRef {
+        "shaders/simple.wgsl".into()
+    }
+}
+
+#[derive(Resource)]
+struct SimpleWorker;
+
+impl ComputeWorker for SimpleWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        AppComputeWorkerBuilder::new(world)
+            .add_uniform("uni", &5f32)
+            .add_staging("values", &[1f32, 2., 3., 4.])
+            .add_pass::<SimpleShader>([4, 1, 1], &["uni", "values"])
+            .build()
+    }
+}
+
+#[derive(Resource)]
+struct BlockingWorker;
+
+impl ComputeWorker for BlockingWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        AppComputeWorkerBuilder::new(world)
+            .add_uniform("uni", &5f32)
+            .add_staging("values", &[1f32, 2., 3., 4.])
+            .add_pass::<SimpleShader>([4, 1, 1], &["uni", "values"])
+            .one_shot()
+            .build()
+    }
+}
+
+#[derive(TypePath)]
+struct ConvergeShader;
+
+impl ComputeShader for ConvergeShader {
+    fn shader() -> ShaderRef {
+        "shaders/converge.wgsl".into()
+    }
+}
+
+#[derive(Resource)]
+struct ConvergeWorker;
+
+impl ComputeWorker for ConvergeWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        AppComputeWorkerBuilder::new(world)
+            .add_staging("values", &[0u32, 3, 7, 9])
+            .add_rw_storage("changed", &0u32)
+            .add_converge(
+                "changed",
+                64,
+                |changed: &u32| *changed == 0,
+                |b| {
+                    b.add_clear("changed")
+                        .add_pass::<ConvergeShader>([4, 1, 1], &["values", "changed"])
+                },
+            )
+            .one_shot()
+            .build()
+    }
+}
+
+fn run_blocking<W: ComputeWorker>(world: &mut World) -> Result<(), Error> {
+    world.resource_scope(|world, mut compute_worker: Mut<AppComputeWorker<W>>| {
+        compute_worker.run_blocking(world)
+    })
+}
+
+#[test]
+fn results_are_readable_on_return() {
+    let mut app = common::build_app_with::<SimpleWorker>(|app| {
+        app.add_plugins(AppComputeWorkerPlugin::<BlockingWorker>::default());
+    });
+
+    run_blocking::<BlockingWorker>(app.world_mut()).unwrap();
+
+    let compute_worker = app.world().resource::<AppComputeWorker<BlockingWorker>>();
+    assert!(compute_worker.ready());
+    assert_eq!(compute_worker.runs(), 1);
+    assert_eq!(compute_worker.read_vec::<f32>("values"), [6., 7., 8., 9.]);
+
+    run_blocking::<BlockingWorker>(app.world_mut()).unwrap();
+    let compute_worker = app.world().resource::<AppComputeWorker<BlockingWorker>>();
+    assert_eq!(
+        compute_worker.read_vec::<f32>("values"),
+        [11., 12., 13., 14.]
+    );
+
+    // The worker's systems keep working afterwards
+    app.update();
+    let compute_worker = app.world().resource::<AppComputeWorker<BlockingWorker>>();
+    assert!(!compute_worker.ready());
+    assert_eq!(compute_worker.runs(), 2);
+}
+
+#[test]
+fn waits_for_convergence() {
+    let mut app = common::build_app_with::<SimpleWorker>(|app| {
+        app.add_plugins(AppComputeWorkerPlugin::<ConvergeWorker>::default());
+    });
+
+    run_blocking::<ConvergeWorker>(app.world_mut()).unwrap();
+
+    let compute_worker = app.world().resource::<AppComputeWorker<ConvergeWorker>>();
+    assert_eq!(compute_worker.read_vec::<u32>("values"), [10; 4]);
+}
+
+#[test]
+fn compiles_pipelines_before_running() {
+    let mut app = App::new();
+    app.add_plugins(DefaultPlugins.build().disable::<bevy::winit::WinitPlugin>())
+        .add_plugins(AppComputePlugin)
+        .add_plugins(AppComputeWorkerPlugin::<BlockingWorker>::default());
+    app.finish();
+    app.cleanup();
+
+    app.world_mut().resource_scope(
+        |world, mut compute_worker: Mut<AppComputeWorker<BlockingWorker>>| {
+            compute_worker
+                .run_blocking_with_timeout(world, Duration::from_secs(10))
+                .unwrap();
+        },
+    );
+
+    let compute_worker = app.world().resource::<AppComputeWorker<BlockingWorker>>();
+    assert_eq!(compute_worker.runs(), 1);
+    assert_eq!(compute_worker.read_vec::<f32>("values"), [6., 7., 8., 9.]);
+}
+
+#[derive(Resource)]
+struct AsyncWorker;
+
+impl ComputeWorker for AsyncWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        AppComputeWorkerBuilder::new(world)
+            .add_uniform("uni", &5f32)
+            .add_staging("values", &[1f32, 2., 3., 4.])
+            .add_pass::<SimpleShader>([4, 1, 1], &["uni", "values"])
+            .one_shot()
+            .asynchronous(None)
+            .build()
+    }
+}
+
+#[test]
+fn completes_interrupted_runs() {
+    let mut app = common::build_app_with::<SimpleWorker>(|app| {
+        app.add_plugins(AppComputeWorkerPlugin::<AsyncWorker>::default());
+    });
+
+    app.world_mut()
+        .resource_mut::<AppComputeWorker<AsyncWorker>>()
+        .execute();
+    app.update();
+    assert!(!app
+        .world()
+        .resource::<AppComputeWorker<AsyncWorker>>()
+        .idle());
+
+    run_blocking::<AsyncWorker>(app.world_mut()).unwrap();
+
+    let compute_worker = app.world().resource::<AppComputeWorker<AsyncWorker>>();
+    assert_eq!(compute_worker.runs(), 2);
+    assert_eq!(
+        compute_worker.read_vec::<f32>("values"),
+        [11., 12., 13., 14.]
+    );
+
+    // Both runs were reported, and the one-shot request was fulfilled
+    let events = app
+        .world()
+        .resource::<Events<ComputeFinished<AsyncWorker>>>();
+    let runs: Vec<u64> = events
+        .get_reader()
+        .read(events)
+        .map(|event| event.run)
+        .collect();
+    assert_eq!(runs, [1, 2]);
+
+    app.update();
+    assert_eq!(
+        app.world()
+            .resource::<AppComputeWorker<AsyncWorker>>()
+            .runs(),
+        2
+    );
+}
@@ -0,0 +1,103 @@
+use bevy::prelude::*;
+use bevy_easy_compute::{prelude::*, Error};
+
+mod common;
+
+#[derive(TypePath)]
+struct SimpleShader;
+
+impl ComputeShader for SimpleShader {
+    fn shader() -> ShaderRef {
+        "shaders/simple.wgsl".into()
+    }
+}
+
+#[derive(Resource)]
+struct BoundsWorker;
+
+impl ComputeWorker for BoundsWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        AppComputeWorkerBuilder::new(world)
+            .add_uniform("uni", &5f32)
+            .add_staging("values", &[1f32, 2., 3., 4.])
+            .add_pass::<SimpleShader>([4, 1, 1], &["uni", "values"])
+            .build()
+    }
+}
+
+#[test]
+fn rejects_out_of_bounds_writes() {
+    let mut app = common::build_app::<BoundsWorker>();
+    let mut compute_worker = app
+        .world_mut()
+        .resource_mut::<AppComputeWorker<BoundsWorker>>();
+
+    let result = compute_worker.try_write_slice("values", &[0f32; 5]);
+    assert!(matches!(
+        result,
+        Err(Error::SizeMismatch {
+            expected: 16,
+            actual: 20,
+            ..
+        })
+    ));
+
+    let result = compute_worker.try_write_slice_at("values", 3, &[0f32; 2]);
+    assert!(matches!(
+        result,
+        Err(Error::SizeMismatch {
+            expected: 16,
+            actual: 20,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn rejects_misaligned_writes() {
+    let mut app = common::build_app::<BoundsWorker>();
+    let mut compute_worker = app
+        .world_mut()
+        .resource_mut::<AppComputeWorker<BoundsWorker>>();
+
+    let result = compute_worker.try_write_slice("values", &[1u8, 2, 3]);
+    assert!(matches!(
+        result,
+        Err(Error::Misaligned {
+            offset: 0,
+            size: 3,
+            alignment: 4,
+            ..
+        })
+    ));
+
+    let result = compute_worker.try_write_slice_at("values", 1, &[1u16, 2]);
+    assert!(matches!(result, Err(Error::Misaligned { offset: 2, .. })));
+}
+
+#[test]
+fn rejects_reads_of_the_wrong_size() {
+    let app = common::build_app::<BoundsWorker>();
+    let compute_worker = app.world().resource::<AppComputeWorker<BoundsWorker>>();
+
+    let result = compute_worker.try_read::<f32>("values");
+    assert!(matches!(
+        result,
+        Err(Error::SizeMismatch {
+            expected: 16,
+            actual: 4,
+            ..
+        })
+    ));
+
+    let result = compute_worker.try_read_vec::<[f32; 3]>("values");
+    assert!(matches!(
+        result,
+        Err(Error::Misaligned { alignment: 12, .. })
+    ));
+
+    assert_eq!(
+        compute_worker.try_read::<[f32; 4]>("values").unwrap(),
+        [6., 7., 8., 9.]
+    );
+}
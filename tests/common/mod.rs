@@ -1,3 +1,5 @@
+#![allow(dead_code)] // Not every test uses every helper
+
 use bevy::{prelude::*, winit::WinitPlugin};
 use bevy_easy_compute::prelude::{
     AppComputePlugin, AppComputeWorker, AppComputeWorkerPlugin, ComputeWorker,
@@ -12,13 +14,33 @@ use bevy_easy_compute::prelude::{
 const MAX_FRAMES_TO_READY: i16 = 10;
 
 pub fn build_app<T>() -> App
+where
+    T: ComputeWorker,
+{
+    build_app_with::<T>(|_| {})
+}
+
+/// Same as [`build_app`], calling `setup` on the app before the plugins are finished.
+pub fn build_app_with<T>(setup: impl FnOnce(&mut App)) -> App
+where
+    T: ComputeWorker,
+{
+    build_app_with_plugin(AppComputeWorkerPlugin::<T>::default(), setup)
+}
+
+/// Same as [`build_app_with`], with a configured worker plugin.
+pub fn build_app_with_plugin<T>(
+    plugin: AppComputeWorkerPlugin<T>,
+    setup: impl FnOnce(&mut App),
+) -> App
 where
     T: ComputeWorker,
 {
     let mut app = App::new();
     app.add_plugins(DefaultPlugins.build().disable::<WinitPlugin>())
         .add_plugins(AppComputePlugin)
-        .add_plugins(AppComputeWorkerPlugin::<T>::default());
+        .add_plugins(plugin);
+    setup(&mut app);
     app.finish();
     app.cleanup();
 
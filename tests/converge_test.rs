@@ -0,0 +1,115 @@
+use bevy::prelude::*;
+use bevy_easy_compute::prelude::*;
+
+mod common;
+
+#[derive(TypePath)]
+struct ConvergeShader;
+
+impl ComputeShader for ConvergeShader {
+    fn shader() -> ShaderRef {
+        "shaders/converge.wgsl".into()
+    }
+}
+
+#[derive(Resource)]
+struct ConvergeWorker;
+
+impl ComputeWorker for ConvergeWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        AppComputeWorkerBuilder::new(world)
+            .add_staging("values", &[0u32, 3, 7, 9])
+            .add_rw_storage("changed", &0u32)
+            .add_staging("snapshot", &[0u32; 4])
+            .add_converge(
+                "changed",
+                64,
+                |changed: &u32| *changed == 0,
+                |b| {
+                    b.add_clear("changed")
+                        .add_pass::<ConvergeShader>([4, 1, 1], &["values", "changed"])
+                },
+            )
+            .label("raise")
+            .add_copy("values", "snapshot", &[])
+            .build()
+    }
+}
+
+#[test]
+fn runs_until_converged() {
+    let app = common::build_app::<ConvergeWorker>();
+    let compute_worker = app.world().resource::<AppComputeWorker<ConvergeWorker>>();
+
+    let result: Vec<u32> = compute_worker.read_vec("values");
+    assert_eq!(result, [10; 4]);
+
+    // The steps following the group run once it converged
+    let result: Vec<u32> = compute_worker.read_vec("snapshot");
+    assert_eq!(result, [10; 4]);
+
+    // 10 iterations to raise 0 to 10, and one more changing nothing
+    assert_eq!(compute_worker.iterations("raise"), 11);
+}
+
+#[derive(Resource)]
+struct CappedWorker;
+
+impl ComputeWorker for CappedWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        AppComputeWorkerBuilder::new(world)
+            .add_staging("values", &[0u32, 3, 7, 9])
+            .add_rw_storage("changed", &0u32)
+            .add_converge(
+                "changed",
+                3,
+                |changed: &u32| *changed == 0,
+                |b| {
+                    b.add_clear("changed")
+                        .add_pass::<ConvergeShader>([4, 1, 1], &["values", "changed"])
+                },
+            )
+            .build()
+    }
+}
+
+#[test]
+fn stops_at_max_iterations() {
+    let app = common::build_app::<CappedWorker>();
+    let compute_worker = app.world().resource::<AppComputeWorker<CappedWorker>>();
+
+    let result: Vec<u32> = compute_worker.read_vec("values");
+    assert_eq!(result, [3, 6, 10, 10]);
+    assert_eq!(compute_worker.iterations(0), 3);
+}
+
+#[derive(Resource)]
+struct NestedWorker;
+
+impl ComputeWorker for NestedWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        let result = AppComputeWorkerBuilder::<Self>::new(world)
+            .add_rw_storage("changed", &0u32)
+            .add_repeat(2, |b| {
+                b.add_converge(
+                    "changed",
+                    3,
+                    |changed: &u32| *changed == 0,
+                    |b| b.add_clear("changed"),
+                )
+            })
+            .try_build();
+
+        assert!(matches!(
+            result,
+            Err(bevy_easy_compute::Error::InvalidStep(_))
+        ));
+
+        AppComputeWorkerBuilder::new(world).build()
+    }
+}
+
+#[test]
+fn rejects_nested_convergence() {
+    common::build_app::<NestedWorker>();
+}
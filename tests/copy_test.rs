@@ -0,0 +1,105 @@
+use bevy::prelude::*;
+use bevy_easy_compute::prelude::*;
+
+mod common;
+
+#[derive(TypePath)]
+struct SimpleShader;
+
+impl ComputeShader for SimpleShader {
+    fn shader() -> ShaderRef {
+        "shaders/simple.wgsl".into()
+    }
+}
+
+#[derive(Resource)]
+struct CopyWorker;
+
+impl ComputeWorker for CopyWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        AppComputeWorkerBuilder::new(world)
+            .add_uniform("uni", &5f32)
+            .add_staging("values", &[1f32, 2., 3., 4.])
+            .add_staging("snapshot", &[0f32; 4])
+            .add_staging("middle", &[0f32; 4])
+            .add_staging("cleared", &[1u32, 2, 3, 4])
+            .add_staging("filled", &[0u32; 4])
+            .add_copy("values", "snapshot", &[])
+            .add_copy("values", "middle", &[(4, 0, 8)])
+            .add_pass::<SimpleShader>([4, 1, 1], &["uni", "values"])
+            .add_clear("cleared")
+            .add_fill("filled", 7u32)
+            .build()
+    }
+}
+
+#[test]
+fn copies_clears_and_fills() {
+    let app = common::build_app::<CopyWorker>();
+    let compute_worker = app.world().resource::<AppComputeWorker<CopyWorker>>();
+
+    let values: Vec<f32> = compute_worker.read_vec("values");
+    let snapshot: Vec<f32> = compute_worker.read_vec("snapshot");
+    assert_eq!(
+        values,
+        snapshot.iter().map(|value| value + 5.).collect::<Vec<_>>()
+    );
+
+    let middle: Vec<f32> = compute_worker.read_vec("middle");
+    assert_eq!(middle, [snapshot[1], snapshot[2], 0., 0.]);
+
+    let cleared: Vec<u32> = compute_worker.read_vec("cleared");
+    assert_eq!(cleared, [0; 4]);
+
+    let filled: Vec<u32> = compute_worker.read_vec("filled");
+    assert_eq!(filled, [7; 4]);
+}
+
+#[derive(Resource)]
+struct OutOfBoundsWorker;
+
+impl ComputeWorker for OutOfBoundsWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        let result = AppComputeWorkerBuilder::<Self>::new(world)
+            .add_staging("a", &[0f32; 4])
+            .add_staging("b", &[0f32; 2])
+            .add_copy("a", "b", &[])
+            .try_build();
+
+        assert!(matches!(
+            result,
+            Err(bevy_easy_compute::Error::SizeMismatch { buffer, .. }) if buffer == "b"
+        ));
+
+        AppComputeWorkerBuilder::new(world).build()
+    }
+}
+
+#[test]
+fn rejects_copies_out_of_bounds() {
+    common::build_app::<OutOfBoundsWorker>();
+}
+
+#[derive(Resource)]
+struct CopyToSelfWorker;
+
+impl ComputeWorker for CopyToSelfWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        let result = AppComputeWorkerBuilder::<Self>::new(world)
+            .add_staging("a", &[0f32; 4])
+            .add_copy("a", "a", &[(0, 8, 8)])
+            .try_build();
+
+        assert!(matches!(
+            result,
+            Err(bevy_easy_compute::Error::CopyToSelf { src, dst }) if src == "a" && dst == "a"
+        ));
+
+        AppComputeWorkerBuilder::new(world).build()
+    }
+}
+
+#[test]
+fn rejects_copies_into_the_same_buffer() {
+    common::build_app::<CopyToSelfWorker>();
+}
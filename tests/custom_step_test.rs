@@ -0,0 +1,46 @@
+use bevy::prelude::*;
+use bevy_easy_compute::prelude::*;
+
+mod common;
+
+#[derive(TypePath)]
+struct SimpleShader;
+
+impl ComputeShader for SimpleShader {
+    fn shader() -> ShaderRef {
+        "shaders/simple.wgsl".into()
+    }
+}
+
+#[derive(Resource)]
+struct CustomStepWorker;
+
+impl ComputeWorker for CustomStepWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        AppComputeWorkerBuilder::new(world)
+            .add_uniform("uni", &5f32)
+            .add_staging("values", &[1f32, 2., 3., 4.])
+            .add_staging("snapshot", &[0f32; 4])
+            .add_custom_step(|ctx: &mut StepContext| {
+                let values = &ctx.buffers["values"];
+                let snapshot = &ctx.buffers["snapshot"];
+                ctx.encoder
+                    .copy_buffer_to_buffer(values, 0, snapshot, 0, values.size());
+            })
+            .add_pass::<SimpleShader>([4, 1, 1], &["uni", "values"])
+            .build()
+    }
+}
+
+#[test]
+fn runs_custom_steps_in_order() {
+    let app = common::build_app::<CustomStepWorker>();
+    let compute_worker = app.world().resource::<AppComputeWorker<CustomStepWorker>>();
+
+    let values: Vec<f32> = compute_worker.read_vec("values");
+    let snapshot: Vec<f32> = compute_worker.read_vec("snapshot");
+    assert_eq!(
+        values,
+        snapshot.iter().map(|value| value + 5.).collect::<Vec<_>>()
+    );
+}
@@ -0,0 +1,86 @@
+use bevy::prelude::*;
+use bevy_easy_compute::prelude::*;
+
+mod common;
+
+#[derive(TypePath)]
+struct ElementsShader;
+
+impl ComputeShader for ElementsShader {
+    fn shader() -> ShaderRef {
+        "shaders/elements.wgsl".into()
+    }
+}
+
+#[derive(TypePath)]
+struct WorkgroupSizeShader;
+
+impl ComputeShader for WorkgroupSizeShader {
+    fn shader() -> ShaderRef {
+        "shaders/workgroup_size.wgsl".into()
+    }
+
+    fn workgroup_size() -> Option<[u32; 3]> {
+        Some([8, 1, 1])
+    }
+}
+
+#[derive(Resource)]
+struct ElementsWorker;
+
+impl ComputeWorker for ElementsWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        AppComputeWorkerBuilder::new(world)
+            .add_staging("reflected", &[0u32; 10])
+            .add_staging("defined", &[0u32; 10])
+            .add_pass_for_elements::<ElementsShader>([10, 1, 1], &["reflected"])
+            .add_pass_for_elements::<WorkgroupSizeShader>([10, 1, 1], &["defined"])
+            .build()
+    }
+}
+
+#[test]
+fn dispatches_enough_workgroups_for_elements() {
+    let app = common::build_app::<ElementsWorker>();
+    let compute_worker = app.world().resource::<AppComputeWorker<ElementsWorker>>();
+
+    let result: Vec<u32> = compute_worker.read_vec("reflected");
+    assert_eq!(result, [1, 1, 1, 1, 2, 2, 2, 2, 3, 3]);
+
+    let result: Vec<u32> = compute_worker.read_vec("defined");
+    assert_eq!(result, [1, 1, 1, 1, 1, 1, 1, 1, 2, 2]);
+}
+
+#[derive(TypePath)]
+struct SpirvShader;
+
+impl ComputeShader for SpirvShader {
+    fn shader() -> ShaderRef {
+        "shaders/elements.spv".into()
+    }
+}
+
+#[derive(Resource)]
+struct SpirvElementsWorker;
+
+impl ComputeWorker for SpirvElementsWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        let result = AppComputeWorkerBuilder::<Self>::new(world)
+            .add_staging("values", &[0u32; 10])
+            .add_pass_for_elements::<SpirvShader>([10, 1, 1], &["values"])
+            .try_build();
+        assert!(matches!(
+            result,
+            Err(bevy_easy_compute::Error::ReflectionUnavailable(_))
+        ));
+
+        AppComputeWorkerBuilder::new(world).build()
+    }
+}
+
+#[test]
+fn rejects_elements_without_workgroup_size() {
+    common::build_app_with::<ElementsWorker>(|app| {
+        app.add_plugins(AppComputeWorkerPlugin::<SpirvElementsWorker>::default());
+    });
+}
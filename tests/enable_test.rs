@@ -0,0 +1,56 @@
+use bevy::prelude::*;
+use bevy_easy_compute::prelude::*;
+
+mod common;
+
+#[derive(TypePath)]
+struct SimpleShader;
+
+impl ComputeShader for SimpleShader {
+    fn shader() -> ShaderRef {
+        "shaders/simple.wgsl".into()
+    }
+}
+
+#[derive(Resource)]
+struct EnableWorker;
+
+impl ComputeWorker for EnableWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        AppComputeWorkerBuilder::new(world)
+            .add_uniform("uni", &5f32)
+            .add_staging("values", &[1f32, 2., 3., 4.])
+            .add_pass::<SimpleShader>([4, 1, 1], &["uni", "values"])
+            .label("add")
+            .build()
+    }
+}
+
+#[test]
+fn skips_disabled_steps() {
+    let mut app = common::build_app::<EnableWorker>();
+
+    let mut compute_worker = app
+        .world_mut()
+        .resource_mut::<AppComputeWorker<EnableWorker>>();
+    let result: Vec<f32> = compute_worker.read_vec("values");
+    assert_eq!(result, [6., 7., 8., 9.]);
+
+    compute_worker.set_step_enabled("add", false);
+    assert!(!compute_worker.step_enabled("add"));
+    app.update();
+
+    let mut compute_worker = app
+        .world_mut()
+        .resource_mut::<AppComputeWorker<EnableWorker>>();
+    let result: Vec<f32> = compute_worker.read_vec("values");
+    assert_eq!(result, [6., 7., 8., 9.]);
+
+    compute_worker.set_step_enabled(0, true);
+    assert!(compute_worker.step_enabled("add"));
+    app.update();
+
+    let compute_worker = app.world().resource::<AppComputeWorker<EnableWorker>>();
+    let result: Vec<f32> = compute_worker.read_vec("values");
+    assert_eq!(result, [11., 12., 13., 14.]);
+}
@@ -0,0 +1,161 @@
+use std::time::Duration;
+
+use bevy::{
+    prelude::*,
+    render::{renderer::RenderDevice, settings::WgpuFeatures},
+};
+use bevy_easy_compute::prelude::*;
+
+mod common;
+
+#[derive(TypePath)]
+struct SimpleShader;
+
+impl ComputeShader for SimpleShader {
+    fn shader() -> ShaderRef {
+        "shaders/simple.wgsl".into()
+    }
+}
+
+#[derive(Resource)]
+struct SimpleWorker;
+
+impl ComputeWorker for SimpleWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        AppComputeWorkerBuilder::new(world)
+            .add_uniform("uni", &5f32)
+            .add_staging("values", &[1f32, 2., 3., 4.])
+            .add_pass::<SimpleShader>([4, 1, 1], &["uni", "values"])
+            .build()
+    }
+}
+
+#[derive(Resource, Default)]
+struct Seen {
+    runs: Vec<u64>,
+    gpu_times: Vec<Option<Duration>>,
+    frames: u32,
+}
+
+fn record_finished(
+    mut finished: EventReader<ComputeFinished<SimpleWorker>>,
+    mut seen: ResMut<Seen>,
+) {
+    for event in finished.read() {
+        seen.runs.push(event.run);
+        seen.gpu_times.push(event.gpu_time);
+    }
+}
+
+fn count_frame(mut seen: ResMut<Seen>) {
+    seen.frames += 1;
+}
+
+#[test]
+fn sends_finished_events() {
+    let mut app = common::build_app::<SimpleWorker>();
+    app.init_resource::<Seen>().add_systems(
+        Update,
+        (
+            record_finished,
+            count_frame.run_if(worker_ready::<SimpleWorker>()),
+        ),
+    );
+
+    for _ in 0..3 {
+        app.update();
+    }
+
+    let runs = app
+        .world()
+        .resource::<AppComputeWorker<SimpleWorker>>()
+        .runs();
+    let seen = app.world().resource::<Seen>();
+
+    // Events sent in `PostUpdate` are read on the next frame
+    assert_eq!(seen.runs.last(), Some(&(runs - 1)));
+    assert!(seen.runs.windows(2).all(|runs| runs[0] + 1 == runs[1]));
+    assert_eq!(seen.frames, 3);
+}
+
+#[test]
+fn measures_gpu_time_with_timestamp_queries() {
+    let mut app = common::build_app::<SimpleWorker>();
+    app.init_resource::<Seen>()
+        .add_systems(Update, record_finished);
+
+    for _ in 0..3 {
+        app.update();
+    }
+
+    let timestamps = app
+        .world()
+        .resource::<RenderDevice>()
+        .features()
+        .contains(WgpuFeatures::TIMESTAMP_QUERY);
+    let seen = app.world().resource::<Seen>();
+
+    assert!(!seen.gpu_times.is_empty());
+    assert!(seen
+        .gpu_times
+        .iter()
+        .all(|gpu_time| gpu_time.is_some() == timestamps));
+}
+
+#[derive(Resource)]
+struct OneShotWorker;
+
+impl ComputeWorker for OneShotWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        AppComputeWorkerBuilder::new(world)
+            .add_uniform("uni", &5f32)
+            .add_staging("values", &[1f32, 2., 3., 4.])
+            .add_pass::<SimpleShader>([4, 1, 1], &["uni", "values"])
+            .one_shot()
+            .asynchronous(Some(Duration::from_millis(50)))
+            .build()
+    }
+}
+
+#[test]
+fn idle_until_executed() {
+    let mut app = common::build_app_with::<SimpleWorker>(|app| {
+        app.add_plugins(AppComputeWorkerPlugin::<OneShotWorker>::default())
+            .init_resource::<Seen>()
+            .add_systems(Update, count_frame.run_if(worker_idle::<OneShotWorker>()));
+    });
+
+    let compute_worker = app.world().resource::<AppComputeWorker<OneShotWorker>>();
+    assert!(compute_worker.idle());
+    assert_eq!(compute_worker.runs(), 0);
+
+    let frames = app.world().resource::<Seen>().frames;
+    assert!(frames > 0);
+
+    app.world_mut()
+        .resource_mut::<AppComputeWorker<OneShotWorker>>()
+        .execute();
+    app.update();
+
+    // Asynchronous runs aren't waited for until `maximum_async_time` is over
+    let compute_worker = app.world().resource::<AppComputeWorker<OneShotWorker>>();
+    assert!(!compute_worker.idle());
+
+    for _ in 0..100 {
+        std::thread::sleep(Duration::from_millis(10));
+        app.update();
+        if app
+            .world()
+            .resource::<AppComputeWorker<OneShotWorker>>()
+            .ready()
+        {
+            break;
+        }
+    }
+
+    let compute_worker = app.world().resource::<AppComputeWorker<OneShotWorker>>();
+    assert!(compute_worker.ready());
+    assert_eq!(compute_worker.runs(), 1);
+    // `count_frame` was skipped on the frames following the submission
+    assert_eq!(app.world().resource::<Seen>().frames, frames + 1);
+}
@@ -0,0 +1,105 @@
+use bevy::prelude::*;
+use bevy_easy_compute::prelude::*;
+
+mod common;
+
+#[derive(TypePath)]
+struct SimpleShader;
+
+impl ComputeShader for SimpleShader {
+    fn shader() -> ShaderRef {
+        "shaders/simple.wgsl".into()
+    }
+}
+
+#[derive(Resource)]
+struct Handles {
+    uni: BufferHandle<f32, Uniform>,
+    values: BufferHandle<f32, Staging>,
+}
+
+#[derive(Resource)]
+struct HandleWorker;
+
+impl ComputeWorker for HandleWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        let mut builder = AppComputeWorkerBuilder::new(world);
+        let uni = builder.add_uniform_handle("uni", &5f32);
+        let values = builder.add_staging_handle("values", &[1f32, 2., 3., 4.]);
+        let worker = builder
+            .add_pass::<SimpleShader>([4, 1, 1], &[uni.name(), values.name()])
+            .build();
+
+        world.insert_resource(Handles { uni, values });
+        worker
+    }
+}
+
+#[test]
+fn reads_and_writes_through_handles() {
+    let mut app = common::build_app::<HandleWorker>();
+    app.add_systems(
+        Update,
+        |mut compute_worker: ResMut<AppComputeWorker<HandleWorker>>, handles: Res<Handles>| {
+            compute_worker.write_uniform_handle(&handles.uni, &2.);
+            compute_worker.write_handle(&handles.values, &[10., 20., 30., 40.]);
+        },
+    );
+    app.update();
+
+    let handles = app.world().resource::<Handles>();
+    let compute_worker = app.world().resource::<AppComputeWorker<HandleWorker>>();
+    assert_eq!(
+        compute_worker.read_handle(&handles.values),
+        [12., 22., 32., 42.]
+    );
+}
+
+#[derive(TypePath)]
+struct PointsShader;
+
+impl ComputeShader for PointsShader {
+    fn shader() -> ShaderRef {
+        "shaders/points.wgsl".into()
+    }
+}
+
+#[derive(Resource)]
+struct PointHandles {
+    points: BufferHandle<Vec3, Staging>,
+}
+
+#[derive(Resource)]
+struct PointsWorker;
+
+impl ComputeWorker for PointsWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        let mut builder = AppComputeWorkerBuilder::new(world);
+        let points = builder.add_staging_handle("points", &[Vec3::ZERO; 2]);
+        let worker = builder
+            .add_pass::<PointsShader>([2, 1, 1], &[points.name()])
+            .build();
+
+        world.insert_resource(PointHandles { points });
+        worker
+    }
+}
+
+#[test]
+fn round_trips_padded_types_through_handles() {
+    let mut app = common::build_app::<PointsWorker>();
+    app.add_systems(
+        Update,
+        |mut compute_worker: ResMut<AppComputeWorker<PointsWorker>>, handles: Res<PointHandles>| {
+            compute_worker.write_handle(&handles.points, &[Vec3::ONE, Vec3::new(4., 5., 6.)]);
+        },
+    );
+    app.update();
+
+    let handles = app.world().resource::<PointHandles>();
+    let compute_worker = app.world().resource::<AppComputeWorker<PointsWorker>>();
+    assert_eq!(
+        compute_worker.read_handle(&handles.points),
+        [Vec3::new(2., 3., 4.), Vec3::new(5., 7., 9.)]
+    );
+}
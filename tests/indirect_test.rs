@@ -0,0 +1,78 @@
+use bevy::prelude::*;
+use bevy_easy_compute::prelude::*;
+
+mod common;
+
+#[derive(TypePath)]
+struct CountShader;
+
+impl ComputeShader for CountShader {
+    fn shader() -> ShaderRef {
+        "shaders/indirect.wgsl".into()
+    }
+
+    fn entry_point<'a>() -> &'a str {
+        "count"
+    }
+}
+
+#[derive(TypePath)]
+struct IncrementShader;
+
+impl ComputeShader for IncrementShader {
+    fn shader() -> ShaderRef {
+        "shaders/indirect.wgsl".into()
+    }
+
+    fn entry_point<'a>() -> &'a str {
+        "increment"
+    }
+}
+
+#[derive(Resource)]
+struct IndirectWorker;
+
+impl ComputeWorker for IndirectWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        AppComputeWorkerBuilder::new(world)
+            .add_staging("values", &[5f32, 1., 7., 2., 0., 0., 0., 0.])
+            .add_rw_storage("args", &[0u32; 3])
+            .add_pass::<CountShader>([1, 1, 1], &["values", "args"])
+            .add_indirect_pass::<IncrementShader>("args", 0, &["values"])
+            .build()
+    }
+}
+
+#[test]
+fn dispatches_from_buffer() {
+    let app = common::build_app::<IndirectWorker>();
+
+    let compute_worker = app.world().resource::<AppComputeWorker<IndirectWorker>>();
+    let result: Vec<f32> = compute_worker.read_vec("values");
+    assert_eq!(result, [6., 2., 8., 3., 0., 0., 0., 0.]);
+}
+
+#[derive(Resource)]
+struct UniformArgsWorker;
+
+impl ComputeWorker for UniformArgsWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        let result = AppComputeWorkerBuilder::<Self>::new(world)
+            .add_staging("values", &[0f32; 4])
+            .add_uniform("args", &UVec4::new(1, 1, 1, 0))
+            .add_indirect_pass::<IncrementShader>("args", 0, &["values"])
+            .try_build();
+
+        assert!(matches!(
+            result,
+            Err(bevy_easy_compute::Error::NotIndirect(name)) if name == "args"
+        ));
+
+        AppComputeWorkerBuilder::new(world).build()
+    }
+}
+
+#[test]
+fn rejects_buffers_without_indirect_usage() {
+    common::build_app::<UniformArgsWorker>();
+}
@@ -0,0 +1,117 @@
+use bevy::{prelude::*, tasks::block_on};
+use bevy_easy_compute::prelude::*;
+
+mod common;
+
+#[derive(TypePath)]
+struct SimpleShader;
+
+impl ComputeShader for SimpleShader {
+    fn shader() -> ShaderRef {
+        "shaders/simple.wgsl".into()
+    }
+}
+
+#[derive(Resource)]
+struct SimpleWorker;
+
+impl ComputeWorker for SimpleWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        AppComputeWorkerBuilder::new(world)
+            .add_uniform("uni", &5f32)
+            .add_staging("values", &[1f32, 2., 3., 4.])
+            .add_pass::<SimpleShader>([4, 1, 1], &["uni", "values"])
+            .build()
+    }
+}
+
+#[derive(Resource)]
+struct JobWorker;
+
+impl ComputeWorker for JobWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        AppComputeWorkerBuilder::new(world)
+            .add_uniform("uni", &5f32)
+            .add_staging("values", &[0f32; 4])
+            .add_pass::<SimpleShader>([4, 1, 1], &["uni", "values"])
+            .one_shot()
+            .build()
+    }
+}
+
+fn build_app() -> App {
+    common::build_app_with::<SimpleWorker>(|app| {
+        app.add_plugins(AppComputeWorkerPlugin::<JobWorker>::default());
+    })
+}
+
+fn submit(app: &mut App, values: [f32; 4]) -> ComputeJob<Vec<f32>> {
+    app.world_mut()
+        .resource_mut::<AppComputeWorker<JobWorker>>()
+        .submit_job(&[("values", bytemuck::cast_slice(&values))], |worker| {
+            worker.read_vec::<f32>("values")
+        })
+}
+
+fn update_until(app: &mut App, job: &ComputeJob<Vec<f32>>) {
+    for _ in 0..30 {
+        if job.is_finished() {
+            return;
+        }
+        app.update();
+    }
+    panic!("Job didn't finish in 30 frames.");
+}
+
+#[test]
+fn jobs_resolve_in_order() {
+    let mut app = build_app();
+
+    let mut first = submit(&mut app, [1., 2., 3., 4.]);
+    let mut second = submit(&mut app, [10., 20., 30., 40.]);
+    assert!(!first.is_finished());
+
+    update_until(&mut app, &first);
+    assert!(!second.is_finished());
+    assert_eq!(first.try_take().unwrap().unwrap(), vec![6., 7., 8., 9.]);
+    assert!(first.try_take().is_none());
+
+    update_until(&mut app, &second);
+    assert_eq!(
+        second.try_take().unwrap().unwrap(),
+        vec![15., 25., 35., 45.]
+    );
+
+    assert_eq!(
+        app.world().resource::<AppComputeWorker<JobWorker>>().runs(),
+        2
+    );
+}
+
+#[test]
+fn jobs_can_be_awaited() {
+    let mut app = build_app();
+
+    let job = submit(&mut app, [2., 4., 6., 8.]);
+    let task = bevy::tasks::AsyncComputeTaskPool::get().spawn(job);
+    while !task.is_finished() {
+        app.update();
+    }
+
+    assert_eq!(block_on(task).unwrap(), vec![7., 9., 11., 13.]);
+}
+
+#[test]
+fn invalid_inputs_fail_the_job() {
+    let mut app = build_app();
+
+    let mut failed = app
+        .world_mut()
+        .resource_mut::<AppComputeWorker<JobWorker>>()
+        .submit_job(&[("unknown", &[0; 4])], |_| ());
+    let mut next = submit(&mut app, [1., 1., 1., 1.]);
+
+    update_until(&mut app, &next);
+    assert!(failed.try_take().unwrap().is_err());
+    assert_eq!(next.try_take().unwrap().unwrap(), vec![6., 6., 6., 6.]);
+}
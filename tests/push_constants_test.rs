@@ -0,0 +1,134 @@
+use bevy::prelude::*;
+use bevy_easy_compute::prelude::*;
+use bytemuck::{bytes_of, cast_slice};
+use wgpu::{Extent3d, PushConstantRange, ShaderStages, TextureFormat, TextureViewDimension};
+
+mod common;
+
+#[derive(TypePath)]
+struct PushConstantsShader;
+
+impl ComputeShader for PushConstantsShader {
+    fn shader() -> ShaderRef {
+        "shaders/push_constants.wgsl".into()
+    }
+
+    fn push_constant_ranges<'a>() -> &'a [PushConstantRange] {
+        &[PushConstantRange {
+            stages: ShaderStages::COMPUTE,
+            range: 0..4,
+        }]
+    }
+}
+
+#[derive(Resource)]
+struct PushConstantsWorker;
+
+impl ComputeWorker for PushConstantsWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        AppComputeWorkerBuilder::new(world)
+            .add_staging("values", &[1f32, 2., 3., 4.])
+            .add_pass_with_push_constants::<PushConstantsShader>(
+                [4, 1, 1],
+                &["values"],
+                bytes_of(&2f32),
+            )
+            .build()
+    }
+}
+
+#[test]
+fn sets_push_constants() {
+    fn update(mut compute_worker: ResMut<AppComputeWorker<PushConstantsWorker>>) {
+        compute_worker.set_push_constants(0, bytes_of(&10f32));
+    }
+
+    let mut app = common::build_app::<PushConstantsWorker>();
+
+    let compute_worker = app
+        .world()
+        .resource::<AppComputeWorker<PushConstantsWorker>>();
+    let result: Vec<f32> = compute_worker.read_vec("values");
+    assert_eq!(result, [2., 4., 6., 8.]);
+
+    app.add_systems(Update, update);
+    app.update();
+
+    let compute_worker = app
+        .world()
+        .resource::<AppComputeWorker<PushConstantsWorker>>();
+    let result: Vec<f32> = compute_worker.read_vec("values");
+    assert_eq!(result, [20., 40., 60., 80.]);
+}
+
+#[test]
+fn rejects_oversized_push_constants() {
+    let mut app = common::build_app::<PushConstantsWorker>();
+    let mut compute_worker = app
+        .world_mut()
+        .resource_mut::<AppComputeWorker<PushConstantsWorker>>();
+
+    let result = compute_worker.try_set_push_constants(0, bytes_of(&[1f32, 2.]));
+    assert!(matches!(
+        result,
+        Err(bevy_easy_compute::Error::InvalidPushConstants {
+            size: 8,
+            max_size: 4,
+            ..
+        })
+    ));
+}
+
+#[derive(TypePath)]
+struct PushConstantsTextureShader;
+
+impl ComputeShader for PushConstantsTextureShader {
+    fn shader() -> ShaderRef {
+        "shaders/push_constants_texture.wgsl".into()
+    }
+
+    fn push_constant_ranges<'a>() -> &'a [PushConstantRange] {
+        &[PushConstantRange {
+            stages: ShaderStages::COMPUTE,
+            range: 0..4,
+        }]
+    }
+}
+
+#[derive(Resource)]
+struct UnfilterableTextureWorker;
+
+impl ComputeWorker for UnfilterableTextureWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        AppComputeWorkerBuilder::new(world)
+            .add_texture(
+                "input",
+                TextureViewDimension::D2,
+                Extent3d {
+                    width: 4,
+                    height: 1,
+                    depth_or_array_layers: 1,
+                },
+                TextureFormat::R32Float,
+                cast_slice(&[1f32, 2., 3., 4.]),
+            )
+            .add_staging("values", &[0f32; 4])
+            .add_pass_with_push_constants::<PushConstantsTextureShader>(
+                [4, 1, 1],
+                &["input", "values"],
+                bytes_of(&2f32),
+            )
+            .build()
+    }
+}
+
+#[test]
+fn binds_unfilterable_textures() {
+    let app = common::build_app::<UnfilterableTextureWorker>();
+
+    let compute_worker = app
+        .world()
+        .resource::<AppComputeWorker<UnfilterableTextureWorker>>();
+    let result: Vec<f32> = compute_worker.read_vec("values");
+    assert_eq!(result, [2., 4., 6., 8.]);
+}
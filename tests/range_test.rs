@@ -0,0 +1,76 @@
+use bevy::prelude::*;
+use bevy_easy_compute::prelude::*;
+
+mod common;
+
+#[derive(TypePath)]
+struct SimpleShader;
+
+impl ComputeShader for SimpleShader {
+    fn shader() -> ShaderRef {
+        "shaders/simple.wgsl".into()
+    }
+}
+
+#[derive(Resource)]
+struct RangeWorker;
+
+impl ComputeWorker for RangeWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        AppComputeWorkerBuilder::new(world)
+            .add_uniform("uni", &5f32)
+            .add_staging("values", &[1f32, 2., 3., 4., 5., 6., 7., 8.])
+            .add_pass::<SimpleShader>([8, 1, 1], &["uni", "values"])
+            .build()
+    }
+}
+
+#[test]
+fn writes_at_offset() {
+    fn write(mut compute_worker: ResMut<AppComputeWorker<RangeWorker>>) {
+        compute_worker.write_slice_at("values", 2, &[10f32, 20.]);
+    }
+
+    let mut app = common::build_app::<RangeWorker>();
+    app.add_systems(Update, write);
+    app.update();
+
+    let compute_worker = app.world().resource::<AppComputeWorker<RangeWorker>>();
+    let result: Vec<f32> = compute_worker.read_vec("values");
+    assert_eq!(result, [11., 12., 15., 25., 15., 16., 17., 18.]);
+
+    let result: Vec<f32> = compute_worker.read_range("values", 2..4);
+    assert_eq!(result, [15., 25.]);
+}
+
+#[test]
+fn copies_staging_range_only() {
+    let mut app = common::build_app::<RangeWorker>();
+    app.world_mut()
+        .resource_mut::<AppComputeWorker<RangeWorker>>()
+        .set_staging_range::<f32>("values", 1..3);
+    app.update();
+
+    let compute_worker = app.world().resource::<AppComputeWorker<RangeWorker>>();
+    let result: Vec<f32> = compute_worker.read_vec("values");
+    assert_eq!(result, [12., 13.]);
+
+    let result: Vec<f32> = compute_worker.read_range("values", 2..3);
+    assert_eq!(result, [13.]);
+
+    assert!(matches!(
+        compute_worker.try_read_range::<f32>("values", 0..1),
+        Err(bevy_easy_compute::Error::OutOfRange { .. })
+    ));
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn rejects_reversed_range() {
+    let app = common::build_app::<RangeWorker>();
+    let compute_worker = app.world().resource::<AppComputeWorker<RangeWorker>>();
+    assert!(matches!(
+        compute_worker.try_read_range::<f32>("values", 3..1),
+        Err(bevy_easy_compute::Error::OutOfRange { .. })
+    ));
+}
@@ -0,0 +1,91 @@
+use bevy::prelude::*;
+use bevy_easy_compute::prelude::*;
+
+mod common;
+
+#[derive(TypePath)]
+struct SimpleShader;
+
+impl ComputeShader for SimpleShader {
+    fn shader() -> ShaderRef {
+        "shaders/simple.wgsl".into()
+    }
+}
+
+#[derive(Resource)]
+struct RepeatWorker;
+
+impl ComputeWorker for RepeatWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        AppComputeWorkerBuilder::new(world)
+            .add_uniform("uni", &5f32)
+            .add_staging("values", &[1f32, 2., 3., 4.])
+            .add_repeat(3, |b| {
+                b.add_pass::<SimpleShader>([4, 1, 1], &["uni", "values"])
+            })
+            .label("add")
+            .build()
+    }
+}
+
+#[test]
+fn repeats_steps() {
+    let mut app = common::build_app::<RepeatWorker>();
+
+    let mut compute_worker = app
+        .world_mut()
+        .resource_mut::<AppComputeWorker<RepeatWorker>>();
+    let result: Vec<f32> = compute_worker.read_vec("values");
+    assert_eq!(result, [16., 17., 18., 19.]);
+
+    compute_worker.set_repeat_count("add", 1);
+    app.update();
+
+    let compute_worker = app.world().resource::<AppComputeWorker<RepeatWorker>>();
+    let result: Vec<f32> = compute_worker.read_vec("values");
+    assert_eq!(result, [21., 22., 23., 24.]);
+}
+
+#[derive(Resource)]
+struct RepeatSwapWorker;
+
+impl ComputeWorker for RepeatSwapWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        AppComputeWorkerBuilder::new(world)
+            .add_uniform("uni", &5f32)
+            .add_staging("a", &[1f32, 2., 3., 4.])
+            .add_staging("b", &[10f32, 20., 30., 40.])
+            .add_repeat(2, |b| {
+                b.add_pass::<SimpleShader>([4, 1, 1], &["uni", "a"])
+                    .label("add")
+                    .add_swap("a", "b")
+            })
+            .label("solve")
+            .build()
+    }
+}
+
+#[test]
+fn repeats_passes_and_swaps() {
+    let mut app = common::build_app::<RepeatSwapWorker>();
+
+    let mut compute_worker = app
+        .world_mut()
+        .resource_mut::<AppComputeWorker<RepeatSwapWorker>>();
+    let result: Vec<f32> = compute_worker.read_vec("a");
+    assert_eq!(result, [6., 7., 8., 9.]);
+    let result: Vec<f32> = compute_worker.read_vec("b");
+    assert_eq!(result, [15., 25., 35., 45.]);
+
+    // Labels inside the group name the steps themselves
+    assert!(compute_worker.try_set_repeat_count("add", 1).is_err());
+    compute_worker.set_repeat_count("solve", 1);
+    app.update();
+
+    // An odd number of swaps leaves the buffers swapped
+    let compute_worker = app.world().resource::<AppComputeWorker<RepeatSwapWorker>>();
+    let result: Vec<f32> = compute_worker.read_vec("a");
+    assert_eq!(result, [15., 25., 35., 45.]);
+    let result: Vec<f32> = compute_worker.read_vec("b");
+    assert_eq!(result, [11., 12., 13., 14.]);
+}
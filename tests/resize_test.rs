@@ -0,0 +1,102 @@
+use bevy::prelude::*;
+use bevy_easy_compute::prelude::*;
+
+mod common;
+
+#[derive(TypePath)]
+struct SimpleShader;
+
+impl ComputeShader for SimpleShader {
+    fn shader() -> ShaderRef {
+        "shaders/simple.wgsl".into()
+    }
+}
+
+#[derive(Resource)]
+struct ResizeWorker;
+
+impl ComputeWorker for ResizeWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        AppComputeWorkerBuilder::new(world)
+            .add_uniform("uni", &5f32)
+            .add_staging("values", &[1f32, 2., 3., 4.])
+            .add_pass::<SimpleShader>([4, 1, 1], &["uni", "values"])
+            .build()
+    }
+}
+
+#[test]
+fn resizes_buffer_keeping_contents() {
+    fn resize(mut compute_worker: ResMut<AppComputeWorker<ResizeWorker>>) {
+        compute_worker.resize_buffer("values", 8 * 4, true);
+    }
+
+    let mut app = common::build_app::<ResizeWorker>();
+    app.add_systems(Update, resize);
+    app.update();
+
+    let compute_worker = app.world().resource::<AppComputeWorker<ResizeWorker>>();
+    let result: Vec<f32> = compute_worker.read_vec("values");
+    assert_eq!(result, [11., 12., 13., 14., 0., 0., 0., 0.]);
+}
+
+#[derive(Resource)]
+struct GrowOnWriteWorker;
+
+impl ComputeWorker for GrowOnWriteWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        AppComputeWorkerBuilder::new(world)
+            .add_uniform("uni", &5f32)
+            .add_staging("values", &[1f32, 2., 3., 4.])
+            .add_pass::<SimpleShader>([4, 1, 1], &["uni", "values"])
+            .grow_on_write()
+            .build()
+    }
+}
+
+#[test]
+fn grows_buffer_on_write() {
+    fn write(mut compute_worker: ResMut<AppComputeWorker<GrowOnWriteWorker>>) {
+        compute_worker.write_slice("values", &[1f32, 2., 3., 4., 5., 6., 7., 8.]);
+    }
+
+    let mut app = common::build_app::<GrowOnWriteWorker>();
+    app.add_systems(Update, write);
+    app.update();
+
+    let compute_worker = app
+        .world()
+        .resource::<AppComputeWorker<GrowOnWriteWorker>>();
+    let result: Vec<f32> = compute_worker.read_vec("values");
+    assert_eq!(result, [6., 7., 8., 9., 5., 6., 7., 8.]);
+}
+
+#[test]
+fn keeps_staging_range_when_resizing() {
+    fn resize(mut compute_worker: ResMut<AppComputeWorker<ResizeWorker>>) {
+        compute_worker.resize_buffer("values", 8 * 4, true);
+    }
+
+    let mut app = common::build_app::<ResizeWorker>();
+    app.world_mut()
+        .resource_mut::<AppComputeWorker<ResizeWorker>>()
+        .set_staging_range::<f32>("values", 1..3);
+    app.add_systems(Update, resize);
+    app.update();
+
+    let compute_worker = app.world().resource::<AppComputeWorker<ResizeWorker>>();
+    let result: Vec<f32> = compute_worker.read_vec("values");
+    assert_eq!(result, [12., 13.]);
+}
+
+#[test]
+fn rejects_size_below_binding_size() {
+    let mut app = common::build_app::<ResizeWorker>();
+    let mut compute_worker = app
+        .world_mut()
+        .resource_mut::<AppComputeWorker<ResizeWorker>>();
+    assert!(matches!(
+        compute_worker.try_resize_buffer("values", 0, false),
+        Err(bevy_easy_compute::Error::SizeMismatch { .. })
+    ));
+}
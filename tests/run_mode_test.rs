@@ -0,0 +1,116 @@
+use std::time::Duration;
+
+use bevy::{prelude::*, time::TimeUpdateStrategy};
+use bevy_easy_compute::prelude::*;
+
+mod common;
+
+#[derive(TypePath)]
+struct SimpleShader;
+
+impl ComputeShader for SimpleShader {
+    fn shader() -> ShaderRef {
+        "shaders/simple.wgsl".into()
+    }
+}
+
+fn read_values<W: ComputeWorker>(app: &App) -> Vec<f32> {
+    app.world()
+        .resource::<AppComputeWorker<W>>()
+        .read_vec("values")
+}
+
+#[derive(Resource)]
+struct EveryNFramesWorker;
+
+impl ComputeWorker for EveryNFramesWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        AppComputeWorkerBuilder::new(world)
+            .add_uniform("uni", &5f32)
+            .add_staging("values", &[1f32, 2., 3., 4.])
+            .add_pass::<SimpleShader>([4, 1, 1], &["uni", "values"])
+            .every_n_frames(3)
+            .build()
+    }
+}
+
+#[test]
+fn runs_every_n_frames() {
+    let mut app = common::build_app::<EveryNFramesWorker>();
+    assert_eq!(read_values::<EveryNFramesWorker>(&app), [6., 7., 8., 9.]);
+
+    for _ in 0..6 {
+        app.update();
+    }
+    assert_eq!(
+        read_values::<EveryNFramesWorker>(&app),
+        [16., 17., 18., 19.]
+    );
+}
+
+#[derive(Resource)]
+struct EveryWorker;
+
+impl ComputeWorker for EveryWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        AppComputeWorkerBuilder::new(world)
+            .add_uniform("uni", &5f32)
+            .add_staging("values", &[1f32, 2., 3., 4.])
+            .add_pass::<SimpleShader>([4, 1, 1], &["uni", "values"])
+            .every(Duration::from_millis(250))
+            .build()
+    }
+}
+
+#[test]
+fn runs_every_period() {
+    let mut app = common::build_app_with::<EveryWorker>(|app| {
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+    });
+    assert_eq!(read_values::<EveryWorker>(&app), [6., 7., 8., 9.]);
+
+    for _ in 0..5 {
+        app.update();
+    }
+    assert_eq!(read_values::<EveryWorker>(&app), [16., 17., 18., 19.]);
+}
+
+#[derive(Resource)]
+struct OnInputChangeWorker;
+
+impl ComputeWorker for OnInputChangeWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        AppComputeWorkerBuilder::new(world)
+            .add_uniform("uni", &5f32)
+            .add_staging("values", &[1f32, 2., 3., 4.])
+            .add_pass::<SimpleShader>([4, 1, 1], &["uni", "values"])
+            .on_input_change()
+            .build()
+    }
+}
+
+#[test]
+fn runs_on_input_change() {
+    let mut app = common::build_app::<OnInputChangeWorker>();
+    assert_eq!(read_values::<OnInputChangeWorker>(&app), [6., 7., 8., 9.]);
+
+    // Staging buffers can only be read on the frames the worker ran
+    app.update();
+    app.update();
+    let compute_worker = app
+        .world()
+        .resource::<AppComputeWorker<OnInputChangeWorker>>();
+    assert!(!compute_worker.ready());
+
+    app.world_mut()
+        .resource_mut::<AppComputeWorker<OnInputChangeWorker>>()
+        .write_slice("values", &[0f32; 4]);
+    app.update();
+    let compute_worker = app
+        .world()
+        .resource::<AppComputeWorker<OnInputChangeWorker>>();
+    assert!(compute_worker.ready());
+    assert_eq!(read_values::<OnInputChangeWorker>(&app), [5.; 4]);
+}
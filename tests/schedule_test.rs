@@ -0,0 +1,95 @@
+use std::time::Duration;
+
+use bevy::{prelude::*, time::TimeUpdateStrategy};
+use bevy_easy_compute::prelude::*;
+
+mod common;
+
+#[derive(TypePath)]
+struct SimpleShader;
+
+impl ComputeShader for SimpleShader {
+    fn shader() -> ShaderRef {
+        "shaders/simple.wgsl".into()
+    }
+}
+
+#[derive(Resource)]
+struct SimpleWorker;
+
+impl ComputeWorker for SimpleWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        AppComputeWorkerBuilder::new(world)
+            .add_uniform("uni", &5f32)
+            .add_staging("values", &[1f32, 2., 3., 4.])
+            .add_pass::<SimpleShader>([4, 1, 1], &["uni", "values"])
+            .build()
+    }
+}
+
+#[test]
+fn runs_in_fixed_update() {
+    let plugin = AppComputeWorkerPlugin::<SimpleWorker>::default().in_schedule(FixedUpdate);
+    let mut app = common::build_app_with_plugin(plugin, |app| {
+        app.insert_resource(Time::<Fixed>::from_duration(Duration::from_millis(50)))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                100,
+            )));
+    });
+
+    let compute_worker = app.world().resource::<AppComputeWorker<SimpleWorker>>();
+    let before: Vec<f32> = compute_worker.read_vec("values");
+
+    // Two fixed timesteps per frame
+    app.update();
+
+    let compute_worker = app.world().resource::<AppComputeWorker<SimpleWorker>>();
+    let after: Vec<f32> = compute_worker.read_vec("values");
+    assert_eq!(
+        after,
+        before.iter().map(|value| value + 10.).collect::<Vec<_>>()
+    );
+}
+
+#[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+enum GameState {
+    #[default]
+    Playing,
+    Paused,
+}
+
+#[test]
+fn runs_only_in_state() {
+    let plugin =
+        AppComputeWorkerPlugin::<SimpleWorker>::default().run_if(in_state(GameState::Playing));
+    let mut app = common::build_app_with_plugin(plugin, |app| {
+        app.init_state::<GameState>();
+    });
+
+    let compute_worker = app.world().resource::<AppComputeWorker<SimpleWorker>>();
+    let before: Vec<f32> = compute_worker.read_vec("values");
+
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::Paused);
+    app.update();
+    app.update();
+
+    // The last results stay readable while the worker is paused
+    let compute_worker = app.world().resource::<AppComputeWorker<SimpleWorker>>();
+    let paused: Vec<f32> = compute_worker.read_vec("values");
+    assert_eq!(paused, before);
+
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::Playing);
+    app.update();
+
+    let compute_worker = app.world().resource::<AppComputeWorker<SimpleWorker>>();
+    assert!(compute_worker.ready());
+    let after: Vec<f32> = compute_worker.read_vec("values");
+    assert_eq!(
+        after,
+        before.iter().map(|value| value + 5.).collect::<Vec<_>>()
+    );
+}
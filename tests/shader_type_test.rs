@@ -0,0 +1,97 @@
+#![allow(dead_code)] // `ShaderType` derives emit unused `check` helpers
+
+use bevy::prelude::*;
+use bevy_easy_compute::prelude::*;
+
+mod common;
+
+#[derive(TypePath)]
+struct ParticlesShader;
+
+impl ComputeShader for ParticlesShader {
+    fn shader() -> ShaderRef {
+        "shaders/particles.wgsl".into()
+    }
+}
+
+#[derive(ShaderType)]
+struct Transform {
+    translation: Vec3,
+    scale: f32,
+}
+
+#[derive(ShaderType, Debug, PartialEq)]
+struct Particles {
+    center: Vec3,
+    count: u32,
+    #[size(runtime)]
+    positions: Vec<Vec3>,
+}
+
+#[derive(Resource)]
+struct ParticlesWorker;
+
+impl ComputeWorker for ParticlesWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        let transform = Transform {
+            translation: Vec3::ZERO,
+            scale: 1.,
+        };
+        let particles = Particles {
+            center: Vec3::ZERO,
+            count: 3,
+            positions: vec![Vec3::ZERO; 3],
+        };
+
+        AppComputeWorkerBuilder::new(world)
+            .add_uniform("transform", &transform)
+            .add_staging("particles", &particles)
+            .add_pass::<ParticlesShader>([3, 1, 1], &["transform", "particles"])
+            .build()
+    }
+}
+
+#[test]
+fn writes_and_reads_padded_structs() {
+    fn write(mut compute_worker: ResMut<AppComputeWorker<ParticlesWorker>>) {
+        let transform = Transform {
+            translation: Vec3::new(1., 2., 3.),
+            scale: 2.,
+        };
+        let particles = Particles {
+            center: Vec3::ONE,
+            count: 3,
+            positions: vec![Vec3::X, Vec3::Y, Vec3::Z],
+        };
+
+        compute_worker.write_shader_type("transform", &transform);
+        compute_worker.write_shader_type("particles", &particles);
+    }
+
+    let mut app = common::build_app::<ParticlesWorker>();
+    app.add_systems(Update, write);
+    app.update();
+
+    let compute_worker = app.world().resource::<AppComputeWorker<ParticlesWorker>>();
+    let particles: Particles = compute_worker.read_shader_type("particles");
+    assert_eq!(
+        &particles,
+        &Particles {
+            center: Vec3::new(3., 4., 5.),
+            count: 3,
+            positions: vec![
+                Vec3::new(3., 2., 3.),
+                Vec3::new(1., 4., 3.),
+                Vec3::new(1., 2., 5.),
+            ],
+        }
+    );
+
+    let mut read_back = Particles {
+        center: Vec3::ZERO,
+        count: 0,
+        positions: Vec::new(),
+    };
+    compute_worker.read_shader_type_into("particles", &mut read_back);
+    assert_eq!(read_back, particles);
+}
@@ -0,0 +1,93 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_easy_compute::prelude::*;
+
+mod common;
+
+#[derive(TypePath)]
+struct SimpleShader;
+
+impl ComputeShader for SimpleShader {
+    fn shader() -> ShaderRef {
+        "shaders/simple.wgsl".into()
+    }
+}
+
+#[derive(Resource)]
+struct SimpleWorker;
+
+impl ComputeWorker for SimpleWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        AppComputeWorkerBuilder::new(world)
+            .add_uniform("uni", &5f32)
+            .add_staging("values", &[1f32, 2., 3., 4.])
+            .add_pass::<SimpleShader>([4, 1, 1], &["uni", "values"])
+            .build()
+    }
+}
+
+#[derive(Resource, Default)]
+struct StartupValues {
+    runs: u64,
+    values: Vec<f32>,
+}
+
+fn read_at_startup(
+    compute_worker: Res<AppComputeWorker<SimpleWorker>>,
+    mut startup: ResMut<StartupValues>,
+) {
+    startup.runs = compute_worker.runs();
+    startup.values = compute_worker.read_vec("values");
+}
+
+#[test]
+fn results_are_readable_at_startup() {
+    let app = common::build_app_with_plugin(
+        AppComputeWorkerPlugin::<SimpleWorker>::default().precompute(),
+        |app| {
+            app.init_resource::<StartupValues>()
+                .add_systems(PostStartup, read_at_startup);
+        },
+    );
+
+    let startup = app.world().resource::<StartupValues>();
+    assert_eq!(startup.runs, 1);
+    assert_eq!(startup.values, [6., 7., 8., 9.]);
+
+    // The worker keeps running in `PostUpdate` afterwards
+    let compute_worker = app.world().resource::<AppComputeWorker<SimpleWorker>>();
+    assert!(compute_worker.runs() > 1);
+}
+
+#[derive(TypePath)]
+struct MissingShader;
+
+impl ComputeShader for MissingShader {
+    fn shader() -> ShaderRef {
+        "shaders/missing.wgsl".into()
+    }
+}
+
+#[derive(Resource)]
+struct MissingShaderWorker;
+
+impl ComputeWorker for MissingShaderWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        AppComputeWorkerBuilder::new(world)
+            .add_staging("values", &[1f32, 2., 3., 4.])
+            .add_pass::<MissingShader>([4, 1, 1], &["values"])
+            .build()
+    }
+}
+
+#[test]
+#[should_panic(expected = "failed to load")]
+fn fails_fast_on_missing_shaders() {
+    common::build_app_with_plugin(
+        AppComputeWorkerPlugin::<MissingShaderWorker>::default()
+            .precompute()
+            .precompute_timeout(Duration::from_secs(5)),
+        |_| {},
+    );
+}
@@ -0,0 +1,103 @@
+use bevy::prelude::*;
+use bevy_easy_compute::prelude::*;
+use wgpu::{
+    Extent3d, SamplerDescriptor, StorageTextureAccess, TextureFormat, TextureViewDimension,
+};
+
+mod common;
+
+const SIZE: Extent3d = Extent3d {
+    width: 4,
+    height: 3,
+    depth_or_array_layers: 1,
+};
+
+#[derive(TypePath)]
+struct TextureShader;
+
+impl ComputeShader for TextureShader {
+    fn shader() -> ShaderRef {
+        "shaders/texture.wgsl".into()
+    }
+}
+
+#[derive(Resource)]
+struct TextureComputeWorker;
+
+impl ComputeWorker for TextureComputeWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        let input: Vec<u8> = (0..SIZE.width * SIZE.height)
+            .flat_map(|i| [i as u8, 0, 0, 255])
+            .collect();
+
+        AppComputeWorkerBuilder::new(world)
+            .add_texture(
+                "input",
+                TextureViewDimension::D2,
+                SIZE,
+                TextureFormat::Rgba8Unorm,
+                &input,
+            )
+            .add_sampler("input_sampler", &SamplerDescriptor::default())
+            .add_staging_texture(
+                "output",
+                TextureViewDimension::D2,
+                SIZE,
+                TextureFormat::R32Float,
+                StorageTextureAccess::WriteOnly,
+            )
+            .add_pass::<TextureShader>(
+                [SIZE.width, SIZE.height, 1],
+                &["input", "input_sampler", "output"],
+            )
+            .build()
+    }
+}
+
+#[test]
+fn reads_back_storage_texture() {
+    fn test(compute_worker: Res<AppComputeWorker<TextureComputeWorker>>) {
+        let result: Vec<f32> = compute_worker
+            .read_texture_vec::<f32>("output")
+            .into_iter()
+            .map(f32::round)
+            .collect();
+        let expected: Vec<f32> = (0..SIZE.width * SIZE.height)
+            .map(|i| i as f32 + 1.)
+            .collect();
+        assert_eq!(result, expected);
+    }
+
+    let mut app = common::build_app::<TextureComputeWorker>();
+    app.add_systems(Update, test);
+    app.update();
+}
+
+#[derive(Resource)]
+struct UnsupportedFormatWorker;
+
+impl ComputeWorker for UnsupportedFormatWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        let result = AppComputeWorkerBuilder::<Self>::new(world)
+            .add_staging_texture(
+                "output",
+                TextureViewDimension::D2,
+                SIZE,
+                TextureFormat::Rgba8UnormSrgb,
+                StorageTextureAccess::WriteOnly,
+            )
+            .try_build();
+
+        assert!(matches!(
+            result,
+            Err(bevy_easy_compute::Error::UnsupportedTextureFormat { texture, .. }) if texture == "output"
+        ));
+
+        AppComputeWorkerBuilder::new(world).build()
+    }
+}
+
+#[test]
+fn rejects_unsupported_storage_formats() {
+    common::build_app::<UnsupportedFormatWorker>();
+}
@@ -0,0 +1,240 @@
+#![allow(dead_code)] // `ShaderType` derives emit unused `check` helpers
+
+use bevy::prelude::*;
+use bevy_easy_compute::prelude::*;
+
+mod common;
+
+#[derive(TypePath)]
+struct SimpleShader;
+
+impl ComputeShader for SimpleShader {
+    fn shader() -> ShaderRef {
+        "shaders/simple.wgsl".into()
+    }
+}
+
+#[derive(Resource)]
+struct ReadOnlyStorageWorker;
+
+impl ComputeWorker for ReadOnlyStorageWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        AppComputeWorkerBuilder::new(world)
+            .add_uniform("uni", &5.)
+            // `values` is bound to `my_storage`, declared `read_write` in the shader
+            .add_storage("values", &[1., 2., 3., 4.])
+            .add_pass::<SimpleShader>([4, 1, 1], &["uni", "values"])
+            .build()
+    }
+}
+
+#[test]
+fn accepts_read_only_buffer_for_read_write_global() {
+    // Only warns, as these buffers were accepted before passes were validated
+    common::build_app::<ReadOnlyStorageWorker>();
+}
+
+#[derive(Resource)]
+struct StorageForUniformWorker;
+
+impl ComputeWorker for StorageForUniformWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        AppComputeWorkerBuilder::new(world)
+            .add_storage("uni", &5.)
+            .add_staging("values", &[1., 2., 3., 4.])
+            .add_pass::<SimpleShader>([4, 1, 1], &["uni", "values"])
+            .build()
+    }
+}
+
+#[test]
+#[should_panic(expected = "expects a var<uniform> for uni")]
+fn rejects_storage_buffer_for_uniform_global() {
+    common::build_app::<StorageForUniformWorker>();
+}
+
+#[derive(Resource)]
+struct UndersizedUniformWorker;
+
+impl ComputeWorker for UndersizedUniformWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        AppComputeWorkerBuilder::new(world)
+            .add_empty_uniform("uni", 2)
+            .add_staging("values", &[1., 2., 3., 4.])
+            .add_pass::<SimpleShader>([4, 1, 1], &["uni", "values"])
+            .build()
+    }
+}
+
+#[test]
+#[should_panic(expected = "Buffer uni is 2 bytes")]
+fn rejects_undersized_buffer() {
+    common::build_app::<UndersizedUniformWorker>();
+}
+
+#[derive(Resource)]
+struct MissingBufferWorker;
+
+impl ComputeWorker for MissingBufferWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        let result = AppComputeWorkerBuilder::<Self>::new(world)
+            .add_staging("values", &[1., 2., 3., 4.])
+            .add_pass::<SimpleShader>([4, 1, 1], &["unknown", "values"])
+            .try_build();
+
+        assert!(matches!(
+            result,
+            Err(bevy_easy_compute::Error::BufferNotFound(name)) if name == "unknown"
+        ));
+
+        AppComputeWorkerBuilder::new(world).build()
+    }
+}
+
+#[test]
+fn reports_missing_buffer_at_build() {
+    common::build_app::<MissingBufferWorker>();
+}
+
+#[derive(TypePath)]
+struct LayoutShader;
+
+impl ComputeShader for LayoutShader {
+    fn shader() -> ShaderRef {
+        "shaders/layout.wgsl".into()
+    }
+}
+
+/// Same members as the WGSL `Segment`, but `[f32; 3]` is not aligned like a `vec3<f32>`.
+#[derive(ShaderType)]
+struct PackedSegment {
+    start: [f32; 3],
+    end: [f32; 3],
+}
+
+#[derive(Resource)]
+struct PackedSegmentWorker;
+
+impl ComputeWorker for PackedSegmentWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        let segment = PackedSegment {
+            start: [0., 0., 0.],
+            end: [1., 0., 0.],
+        };
+
+        AppComputeWorkerBuilder::new(world)
+            .add_storage("segment", &segment)
+            .add_staging("lengths", &[0f32])
+            .add_pass::<LayoutShader>([1, 1, 1], &["segment", "lengths"])
+            .build()
+    }
+}
+
+#[test]
+#[should_panic(expected = "offset of `segment.end` is 12 in Rust, but 16 in WGSL")]
+fn rejects_mismatched_struct_layout() {
+    common::build_app::<PackedSegmentWorker>();
+}
+
+#[derive(ShaderType)]
+struct Segment {
+    start: Vec3,
+    end: Vec3,
+}
+
+#[derive(Resource)]
+struct SegmentWorker;
+
+impl ComputeWorker for SegmentWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        let segment = Segment {
+            start: Vec3::ZERO,
+            end: Vec3::new(3., 4., 0.),
+        };
+
+        AppComputeWorkerBuilder::new(world)
+            .add_storage("segment", &segment)
+            .add_staging("lengths", &[0f32])
+            .add_pass::<LayoutShader>([1, 1, 1], &["segment", "lengths"])
+            .build()
+    }
+}
+
+#[test]
+fn accepts_matching_struct_layout() {
+    fn test(compute_worker: Res<AppComputeWorker<SegmentWorker>>) {
+        let result: Vec<f32> = compute_worker.read_vec("lengths");
+        assert_eq!(result, [5.]);
+    }
+
+    let mut app = common::build_app::<SegmentWorker>();
+    app.add_systems(Update, test);
+    app.update();
+}
+
+#[derive(TypePath)]
+struct ReorderedShader;
+
+impl ComputeShader for ReorderedShader {
+    fn shader() -> ShaderRef {
+        "shaders/layout_reordered.wgsl".into()
+    }
+}
+
+/// Same size as the WGSL `Packed`, but the `vec2<f32>` comes first.
+#[derive(ShaderType)]
+struct Reordered {
+    a: Vec2,
+    b: f32,
+    c: f32,
+}
+
+#[derive(Resource)]
+struct ReorderedWorker;
+
+impl ComputeWorker for ReorderedWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        let packed = Reordered {
+            a: Vec2::new(1., 2.),
+            b: 3.,
+            c: 4.,
+        };
+
+        AppComputeWorkerBuilder::new(world)
+            .add_storage("packed", &packed)
+            .add_staging("sums", &[0f32])
+            .add_pass::<ReorderedShader>([1, 1, 1], &["packed", "sums"])
+            .build()
+    }
+}
+
+#[test]
+#[should_panic(expected = "size of `packed.c` is 4 in Rust, but 8 in WGSL")]
+fn rejects_reordered_struct_layout() {
+    common::build_app::<ReorderedWorker>();
+}
+
+#[derive(Resource)]
+struct PaddedArrayWorker;
+
+impl ComputeWorker for PaddedArrayWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        let segment = Segment {
+            start: Vec3::ZERO,
+            end: Vec3::new(3., 4., 0.),
+        };
+
+        AppComputeWorkerBuilder::new(world)
+            .add_storage("segment", &segment)
+            // `lengths` is an `array<f32>`, but each `Vec3` is padded to 16 bytes
+            .add_staging("lengths", &vec![Vec3::ZERO; 2])
+            .add_pass::<LayoutShader>([1, 1, 1], &["segment", "lengths"])
+            .build()
+    }
+}
+
+#[test]
+#[should_panic(expected = "offset of `lengths[3]` is 16 in Rust, but 12 in WGSL")]
+fn rejects_padded_array_stride() {
+    common::build_app::<PaddedArrayWorker>();
+}
@@ -0,0 +1,74 @@
+use bevy::prelude::*;
+use bevy_easy_compute::prelude::*;
+
+mod common;
+
+#[derive(TypePath)]
+struct SimpleShader;
+
+impl ComputeShader for SimpleShader {
+    fn shader() -> ShaderRef {
+        "shaders/simple.wgsl".into()
+    }
+}
+
+#[derive(Resource)]
+struct WorkgroupsWorker;
+
+impl ComputeWorker for WorkgroupsWorker {
+    fn build(world: &mut World) -> AppComputeWorker<Self> {
+        AppComputeWorkerBuilder::new(world)
+            .add_uniform("uni", &5f32)
+            .add_staging("values", &[1f32, 2., 3., 4.])
+            .add_pass::<SimpleShader>([2, 1, 1], &["uni", "values"])
+            .label("add")
+            .build()
+    }
+}
+
+#[test]
+fn sets_workgroups_by_label() {
+    let mut app = common::build_app::<WorkgroupsWorker>();
+
+    let mut compute_worker = app
+        .world_mut()
+        .resource_mut::<AppComputeWorker<WorkgroupsWorker>>();
+    let result: Vec<f32> = compute_worker.read_vec("values");
+    assert_eq!(result, [6., 7., 3., 4.]);
+
+    compute_worker.set_workgroups("add", [4, 1, 1]);
+    app.update();
+
+    let compute_worker = app.world().resource::<AppComputeWorker<WorkgroupsWorker>>();
+    let result: Vec<f32> = compute_worker.read_vec("values");
+    assert_eq!(result, [11., 12., 8., 9.]);
+}
+
+#[test]
+fn sets_workgroups_by_index() {
+    let mut app = common::build_app::<WorkgroupsWorker>();
+
+    let mut compute_worker = app
+        .world_mut()
+        .resource_mut::<AppComputeWorker<WorkgroupsWorker>>();
+    compute_worker.set_workgroups(0, [1, 1, 1]);
+    app.update();
+
+    let compute_worker = app.world().resource::<AppComputeWorker<WorkgroupsWorker>>();
+    let result: Vec<f32> = compute_worker.read_vec("values");
+    assert_eq!(result, [11., 7., 3., 4.]);
+}
+
+#[test]
+fn rejects_unknown_steps() {
+    let mut app = common::build_app::<WorkgroupsWorker>();
+
+    let mut compute_worker = app
+        .world_mut()
+        .resource_mut::<AppComputeWorker<WorkgroupsWorker>>();
+    assert!(matches!(
+        compute_worker.try_set_workgroups("blur", [1, 1, 1]),
+        Err(bevy_easy_compute::Error::InvalidStep(step)) if step == "blur"
+    ));
+    assert!(compute_worker.try_set_workgroups(1, [1, 1, 1]).is_err());
+}